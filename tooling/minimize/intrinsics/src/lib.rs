@@ -22,6 +22,26 @@ pub fn exit() {
     std::process::exit(0);
 }
 
+pub fn exit_with_code(code: i32) {
+    std::process::exit(code);
+}
+
+pub fn abort() {
+    std::process::abort();
+}
+
+pub fn likely(b: bool) -> bool {
+    b
+}
+
+pub fn unlikely(b: bool) -> bool {
+    b
+}
+
+pub fn black_box<T>(t: T) -> T {
+    std::hint::black_box(t)
+}
+
 pub unsafe fn allocate(size: usize, align: usize) -> *mut u8 {
     let layout = Layout::from_size_align(size, align).unwrap();
     System.allocate(layout).unwrap().as_ptr() as *mut u8
@@ -131,11 +151,31 @@ pub unsafe fn atomic_store(ptr: *mut u32, value: u32) {
     atomic.store(value, Ordering::SeqCst);
 }
 
+pub unsafe fn atomic_store_relaxed(ptr: *mut u32, value: u32) {
+    let atomic = AtomicU32::from_ptr(ptr);
+    atomic.store(value, Ordering::Relaxed);
+}
+
+pub unsafe fn atomic_store_release(ptr: *mut u32, value: u32) {
+    let atomic = AtomicU32::from_ptr(ptr);
+    atomic.store(value, Ordering::Release);
+}
+
 pub unsafe fn atomic_load(ptr: *mut u32) -> u32 {
     let atomic = AtomicU32::from_ptr(ptr);
     atomic.load(Ordering::SeqCst)
 }
 
+pub unsafe fn atomic_load_relaxed(ptr: *mut u32) -> u32 {
+    let atomic = AtomicU32::from_ptr(ptr);
+    atomic.load(Ordering::Relaxed)
+}
+
+pub unsafe fn atomic_load_acquire(ptr: *mut u32) -> u32 {
+    let atomic = AtomicU32::from_ptr(ptr);
+    atomic.load(Ordering::Acquire)
+}
+
 pub unsafe fn compare_exchange(ptr: *mut u32, current: u32, new: u32) -> u32 {
     let atomic = AtomicU32::from_ptr(ptr);
     let res = atomic.compare_exchange(current, new, Ordering::SeqCst, Ordering::SeqCst);
@@ -4,10 +4,11 @@ use crate::*;
 // Those can be found by grepping "IGNORED".
 
 /// A MIR statement becomes either a MiniRust statement or an intrinsic with some arguments, which
-/// then starts a new basic block.
+/// then starts a new basic block. Statements without any effect are dropped.
 enum StatementResult {
     Statement(Statement),
     Intrinsic { intrinsic: IntrinsicOp, destination: PlaceExpr, arguments: List<ValueExpr> },
+    Nop,
 }
 
 impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
@@ -17,10 +18,13 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
         let mut cur_block_name = name;
         let mut cur_block_statements = List::new();
         for stmt in bb.statements.iter() {
+            let location = (self.fn_name, cur_block_name, cur_block_statements.len());
+            self.cx.spans.insert(location, stmt.source_info.span);
             match self.translate_stmt(stmt) {
                 StatementResult::Statement(stmt) => {
                     cur_block_statements.push(stmt);
                 }
+                StatementResult::Nop => {}
                 StatementResult::Intrinsic { intrinsic, destination, arguments } => {
                     // Generate a fresh bb name.
                     let next_bb = self.fresh_bb_name();
@@ -40,6 +44,8 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                 }
             }
         }
+        let location = (self.fn_name, cur_block_name, cur_block_statements.len());
+        self.cx.spans.insert(location, bb.terminator().source_info.span);
         let terminator = self.translate_terminator(bb.terminator());
         let cur_block = BasicBlock { statements: cur_block_statements, terminator };
         let old = self.blocks.insert(cur_block_name, cur_block);
@@ -63,7 +69,6 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                         };
                     }
                     rs::Rvalue::Cast(rs::CastKind::PointerWithExposedProvenance, operand, _) => {
-                        // TODO untested so far! (Can't test because of `predict`)
                         let operand = self.translate_operand(operand, span);
                         return StatementResult::Intrinsic {
                             intrinsic: IntrinsicOp::PointerWithExposedProvenance,
@@ -71,6 +76,48 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                             arguments: list![operand],
                         };
                     }
+                    rs::Rvalue::ThreadLocalRef(def_id) => {
+                        // The static's allocation holds the initial value for every thread.
+                        let alloc_id = self.tcx.reserve_and_set_static_alloc(*def_id);
+                        let global = self.translate_alloc_id(alloc_id);
+                        return StatementResult::Intrinsic {
+                            intrinsic: IntrinsicOp::ThreadLocalAddr { global },
+                            destination,
+                            arguments: list![],
+                        };
+                    }
+                    rs::Rvalue::NullaryOp(rs::NullOp::OffsetOf(fields), ty) => {
+                        let ty = self.translate_ty(*ty, span);
+                        let path = fields
+                            .iter()
+                            .map(|(variant, field)| {
+                                if variant != rs::abi::FIRST_VARIANT {
+                                    rs::span_bug!(span, "offset_of on enum variants not supported");
+                                }
+                                Int::from(field.as_usize())
+                            })
+                            .collect();
+                        return StatementResult::Intrinsic {
+                            intrinsic: IntrinsicOp::OffsetOf { ty, path },
+                            destination,
+                            arguments: list![],
+                        };
+                    }
+                    rs::Rvalue::NullaryOp(
+                        null_op @ (rs::NullOp::SizeOf | rs::NullOp::AlignOf),
+                        ty,
+                    ) => {
+                        let ty = self.translate_ty(*ty, span);
+                        let intrinsic = match null_op {
+                            rs::NullOp::SizeOf => IntrinsicOp::SizeOf { ty },
+                            _ => IntrinsicOp::AlignOf { ty },
+                        };
+                        return StatementResult::Intrinsic {
+                            intrinsic,
+                            destination,
+                            arguments: list![],
+                        };
+                    }
                     _ => {}
                 }
                 let source = self.translate_rvalue(rval, span);
@@ -82,8 +129,14 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                 Statement::StorageDead(self.local_name_map[&local]),
             rs::StatementKind::Retag(kind, place) => {
                 let place = self.translate_place(place, span);
-                let fn_entry = matches!(kind, rs::RetagKind::FnEntry);
-                Statement::Validate { place, fn_entry }
+                // MiniRust's `Validate` is MIR's `Retag`. Raw retags are done by `AddrOf` already,
+                // so they are like all other retags here.
+                let kind = match kind {
+                    rs::RetagKind::FnEntry => RetagKind::FnEntry,
+                    rs::RetagKind::TwoPhase => RetagKind::TwoPhase,
+                    rs::RetagKind::Raw | rs::RetagKind::Default => RetagKind::Default,
+                };
+                Statement::Validate { place, kind }
             }
             rs::StatementKind::Deinit(place) => {
                 let place = self.translate_place(place, span);
@@ -108,6 +161,32 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                     arguments: list![op],
                 };
             }
+            rs::StatementKind::Intrinsic(box rs::NonDivergingIntrinsic::CopyNonOverlapping(
+                rs::CopyNonOverlapping { src, dst, count },
+            )) => {
+                let &rs::TyKind::RawPtr(pointee, _mutbl) = src.ty(&self.body, self.tcx).kind()
+                else {
+                    rs::span_bug!(span, "`copy_nonoverlapping` source is not a raw pointer");
+                };
+                let ty = self.translate_ty(pointee, span);
+                let arguments = list![
+                    self.translate_operand(src, span),
+                    self.translate_operand(dst, span),
+                    self.translate_operand(count, span)
+                ];
+                return StatementResult::Intrinsic {
+                    intrinsic: IntrinsicOp::CopyNonOverlapping { ty },
+                    destination: build::zst_place(),
+                    arguments,
+                };
+            }
+            // These only matter for the borrow checker, or they carry no information at all.
+            // `PlaceMention` is IGNORED currently: Miri checks that the place is based on a
+            // dereferenceable pointer, but MiniRust has no statement that only evaluates a place.
+            rs::StatementKind::FakeRead(..)
+            | rs::StatementKind::PlaceMention(..)
+            | rs::StatementKind::AscribeUserType(..)
+            | rs::StatementKind::Nop => return StatementResult::Nop,
             x => rs::span_bug!(span, "StatementKind not supported: {x:?}"),
         })
     }
@@ -144,6 +223,8 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                         ),
                 };
 
+                // rustc already lowers range patterns like `1..=5` to comparisons that are switched
+                // on as booleans, so the cases here are only ever the values listed in the MIR.
                 let cases = targets
                     .iter()
                     .map(|(value, target)| {
@@ -154,12 +235,45 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                 let fallback_block = targets.otherwise();
                 let fallback = self.bb_name_map[&fallback_block];
 
-                Terminator::Switch { value, cases, fallback }
+                build::canonicalize_switch(Terminator::Switch { value, cases, fallback })
             }
             rs::TerminatorKind::Unreachable => Terminator::Unreachable,
-            // those are IGNORED currently.
-            rs::TerminatorKind::Drop { target, .. } | rs::TerminatorKind::Assert { target, .. } =>
-                Terminator::Goto(self.bb_name_map[&target]),
+            rs::TerminatorKind::InlineAsm { targets, .. } => {
+                if !self.unmodeled_asm.iter().any(|(f, _)| *f == self.fn_name) {
+                    let path = self.tcx.def_path_str(self.body.source.def_id());
+                    self.cx.unmodeled_asm.push((self.fn_name, path));
+                }
+                match (self.asm_mode, targets.first()) {
+                    // The fallthrough block is the first target, the others are `asm goto` labels.
+                    (AsmMode::Noop, Some(target)) => Terminator::Goto(self.bb_name_map[target]),
+                    // Reaching the assembly is UB.
+                    (AsmMode::Ub, Some(target)) =>
+                        self.unreachable_before(self.bb_name_map[target]),
+                    // Without a fallthrough (`options(noreturn)`) there is nowhere to continue.
+                    (_, None) => Terminator::Unreachable,
+                }
+            }
+            // Resuming a coroutine that has returned (or panicked) fails this assertion.
+            // Rust only promises a panic for coroutine literals; MiniRust makes it UB.
+            rs::TerminatorKind::Assert {
+                msg:
+                    box (rs::AssertKind::ResumedAfterReturn(_) | rs::AssertKind::ResumedAfterPanic(_)),
+                ..
+            } => Terminator::Unreachable,
+            rs::TerminatorKind::Assert { cond, expected, target, .. } => {
+                // If the assertion fails, we panic.
+                let panic_bb = self.fresh_bb_name();
+                let panic_blk = BasicBlock { statements: List::new(), terminator: self.panic() };
+                self.blocks.insert(panic_bb, panic_blk);
+
+                let cond = self.translate_operand(cond, span);
+                let target = self.bb_name_map[target];
+                let (then_bb, else_bb) =
+                    if *expected { (target, panic_bb) } else { (panic_bb, target) };
+                build::if_(cond, then_bb.0.get_internal(), else_bb.0.get_internal())
+            }
+            // this is IGNORED currently.
+            rs::TerminatorKind::Drop { target, .. } => Terminator::Goto(self.bb_name_map[&target]),
             x => rs::span_bug!(span, "terminator not supported: {x:?}"),
         }
     }
@@ -172,18 +286,40 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
         target: &Option<rs::BasicBlock>,
         span: rs::Span,
     ) -> Terminator {
-        // For now we only support calling specific functions, not function pointers.
-        let rs::Operand::Constant(box f1) = func else { panic!() };
-        let rs::mir::Const::Val(_, f2) = f1.const_ else { panic!() };
-        let &rs::TyKind::FnDef(f, substs_ref) = f2.kind() else { panic!() };
+        let &rs::TyKind::FnDef(f, substs_ref) = func.ty(&self.body, self.tcx).kind() else {
+            // A call through a function pointer. The callee is only known at runtime, and it is
+            // UB if its signature does not match the arguments and return place given here.
+            return Terminator::Call {
+                callee: self.translate_operand(func, span),
+                arguments: self.translate_call_args(func, args),
+                ret: self.translate_place(destination, span),
+                next_block: target.as_ref().map(|t| self.bb_name_map[t]),
+            };
+        };
         let instance =
             rs::Instance::expect_resolve(self.tcx, rs::ParamEnv::reveal_all(), f, substs_ref);
 
-        if self.tcx.crate_name(f.krate).as_str() == "intrinsics" {
+        if let rs::InstanceDef::Virtual(_, idx) = instance.def {
+            // A call of a trait method on a trait object. The method is looked up in the vtable
+            // of the `self` pointer, and gets called with the data pointer as `self`.
+            let self_ptr = self.translate_operand(&args[0].node, args[0].span);
+            let mut arguments = self.translate_call_args(func, args);
+            arguments.set(Int::ZERO, ArgumentExpr::ByValue(build::get_thin_pointer(self_ptr)));
+            Terminator::Call {
+                callee: build::vtable_method_lookup(self_ptr, idx as u32),
+                arguments,
+                ret: self.translate_place(destination, span),
+                next_block: target.as_ref().map(|t| self.bb_name_map[t]),
+            }
+        } else if self.tcx.crate_name(f.krate).as_str() == "intrinsics" {
             let intrinsic = match self.tcx.item_name(f).as_str() {
                 "print" => IntrinsicOp::PrintStdout,
                 "eprint" => IntrinsicOp::PrintStderr,
-                "exit" => IntrinsicOp::Exit,
+                "exit" | "exit_with_code" => IntrinsicOp::Exit,
+                "abort" => IntrinsicOp::Abort,
+                "likely" => IntrinsicOp::Likely,
+                "unlikely" => IntrinsicOp::Unlikely,
+                "black_box" => IntrinsicOp::BlackBox,
                 "allocate" => IntrinsicOp::Allocate,
                 "deallocate" => IntrinsicOp::Deallocate,
                 "spawn" => IntrinsicOp::Spawn,
@@ -191,8 +327,12 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                 "create_lock" => IntrinsicOp::Lock(IntrinsicLockOp::Create),
                 "acquire" => IntrinsicOp::Lock(IntrinsicLockOp::Acquire),
                 "release" => IntrinsicOp::Lock(IntrinsicLockOp::Release),
-                "atomic_store" => IntrinsicOp::AtomicStore,
-                "atomic_load" => IntrinsicOp::AtomicLoad,
+                "atomic_store" => IntrinsicOp::AtomicStore(AtomicOrdering::SeqCst),
+                "atomic_store_relaxed" => IntrinsicOp::AtomicStore(AtomicOrdering::Relaxed),
+                "atomic_store_release" => IntrinsicOp::AtomicStore(AtomicOrdering::Release),
+                "atomic_load" => IntrinsicOp::AtomicLoad(AtomicOrdering::SeqCst),
+                "atomic_load_relaxed" => IntrinsicOp::AtomicLoad(AtomicOrdering::Relaxed),
+                "atomic_load_acquire" => IntrinsicOp::AtomicLoad(AtomicOrdering::Acquire),
                 "compare_exchange" => IntrinsicOp::AtomicCompareExchange,
                 "atomic_fetch_add" => IntrinsicOp::AtomicFetchAndOp(IntBinOp::Add),
                 "atomic_fetch_sub" => IntrinsicOp::AtomicFetchAndOp(IntBinOp::Sub),
@@ -201,12 +341,149 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
             Terminator::Intrinsic {
                 intrinsic,
                 arguments: args.iter().map(|x| self.translate_operand(&x.node, x.span)).collect(),
-                ret: self.translate_place(&destination, span),
+                ret: self.translate_place(destination, span),
+                next_block: target.as_ref().map(|t| self.bb_name_map[t]),
+            }
+        } else if Some(f) == self.tcx.lang_items().exchange_malloc_fn() {
+            // `Box::new` allocates via this function, which ends up in the global allocator
+            // that we cannot translate. It has the same signature as our `Allocate` intrinsic.
+            Terminator::Intrinsic {
+                intrinsic: IntrinsicOp::Allocate,
+                arguments: args.iter().map(|x| self.translate_operand(&x.node, x.span)).collect(),
+                ret: self.translate_place(destination, span),
+                next_block: target.as_ref().map(|t| self.bb_name_map[t]),
+            }
+        } else if let rs::InstanceDef::Intrinsic(_) = instance.def {
+            // Rust intrinsics have no MIR body we could translate.
+            match self.tcx.item_name(f).as_str() {
+                // Used by e.g. `MaybeUninit::assume_init`. This panics if the type is uninhabited.
+                "assert_inhabited" => {
+                    let ty = substs_ref.type_at(0);
+                    if self.rs_layout_of(ty).abi().is_uninhabited() {
+                        self.panic()
+                    } else {
+                        Terminator::Goto(self.bb_name_map[&target.unwrap()])
+                    }
+                }
+                // Like codegen, we evaluate these at translation time since their result only
+                // depends on the type arguments.
+                "type_id" | "type_name" | "needs_drop" | "variant_count" => {
+                    let value = self
+                        .tcx
+                        .const_eval_instance(rs::ParamEnv::reveal_all(), instance, span)
+                        .unwrap();
+                    // The constant has the intrinsic's return type, e.g. `u128` for `type_id`.
+                    let sig = instance.ty(self.tcx, rs::ParamEnv::reveal_all()).fn_sig(self.tcx);
+                    let sig = self
+                        .tcx
+                        .normalize_erasing_late_bound_regions(rs::ParamEnv::reveal_all(), sig);
+                    let source =
+                        self.translate_const(&rs::mir::Const::Val(value, sig.output()), span);
+                    let destination = self.translate_place(destination, span);
+                    self.assign_and_goto(destination, source, target.unwrap())
+                }
+                // Used by `mem::swap`, swapping a single value.
+                "typed_swap" => {
+                    let ty = self.translate_ty(substs_ref.type_at(0), span);
+                    let mut arguments: List<ValueExpr> =
+                        args.iter().map(|x| self.translate_operand(&x.node, x.span)).collect();
+                    arguments.push(build::const_int_typed::<usize>(Int::ONE));
+                    Terminator::Intrinsic {
+                        intrinsic: IntrinsicOp::MemSwap { ty },
+                        arguments,
+                        ret: self.translate_place(destination, span),
+                        next_block: target.as_ref().map(|t| self.bb_name_map[t]),
+                    }
+                }
+                // For unsized types, these compute the result from the pointer metadata.
+                name @ ("size_of_val" | "min_align_of_val") => {
+                    let ty = self.translate_ty(substs_ref.type_at(0), span);
+                    let intrinsic = match name {
+                        "size_of_val" => IntrinsicOp::SizeOfVal { ty },
+                        _ => IntrinsicOp::AlignOfVal { ty },
+                    };
+                    Terminator::Intrinsic {
+                        intrinsic,
+                        arguments: args
+                            .iter()
+                            .map(|x| self.translate_operand(&x.node, x.span))
+                            .collect(),
+                        ret: self.translate_place(destination, span),
+                        next_block: target.as_ref().map(|t| self.bb_name_map[t]),
+                    }
+                }
+                name => {
+                    // These have a MiniRust intrinsic with the same signature.
+                    let intrinsic = match name {
+                        "ptr_guaranteed_cmp" => IntrinsicOp::PtrGuaranteedCmp,
+                        "rotate_left" => IntrinsicOp::RotateLeft,
+                        "rotate_right" => IntrinsicOp::RotateRight,
+                        "bitreverse" => IntrinsicOp::ReverseBits,
+                        "bswap" => IntrinsicOp::ByteSwap,
+                        "likely" => IntrinsicOp::Likely,
+                        "unlikely" => IntrinsicOp::Unlikely,
+                        // `core::intrinsics::abort` cleanly stops the program, while reaching
+                        // `core::hint::unreachable_unchecked` is UB.
+                        "abort" => IntrinsicOp::Abort,
+                        "unreachable" => IntrinsicOp::UnreachableUnchecked,
+                        name => {
+                            // Only calling the intrinsic is a problem, so we can still translate
+                            // the rest of the function.
+                            let loc = self.tcx.sess.source_map().span_to_diagnostic_string(span);
+                            self.cx.translation_errors.push(format!(
+                                "{loc}: unsupported Rust intrinsic `{name}`, \
+                                 using an `Unreachable` terminator"
+                            ));
+                            return match target {
+                                Some(target) => self.unreachable_before(self.bb_name_map[target]),
+                                None => Terminator::Unreachable,
+                            };
+                        }
+                    };
+                    Terminator::Intrinsic {
+                        intrinsic,
+                        arguments: args
+                            .iter()
+                            .map(|x| self.translate_operand(&x.node, x.span))
+                            .collect(),
+                        ret: self.translate_place(destination, span),
+                        next_block: target.as_ref().map(|t| self.bb_name_map[t]),
+                    }
+                }
+            }
+        } else if Some(f) == core_item(self.tcx, &["hint", "unreachable_unchecked"]) {
+            // In the MIR of the standard library, the `unreachable` intrinsic call in here has
+            // already been lowered away, so we emit the intrinsic ourselves.
+            build::unreachable_unchecked(None)
+        } else if self.tcx.is_diagnostic_item(rs::sym::ptr_copy_nonoverlapping, f) {
+            // The body of `ptr::copy_nonoverlapping` only runs its precondition check behind
+            // `ub_checks()`, and the storage of the check's temporaries is not balanced on the
+            // path that skips it. So we call the intrinsic directly; it checks the same UB.
+            let ty = self.translate_ty(substs_ref.type_at(0), span);
+            Terminator::Intrinsic {
+                intrinsic: IntrinsicOp::CopyNonOverlapping { ty },
+                arguments: args.iter().map(|x| self.translate_operand(&x.node, x.span)).collect(),
+                ret: self.translate_place(destination, span),
                 next_block: target.as_ref().map(|t| self.bb_name_map[t]),
             }
-        } else if is_panic_fn(&instance.to_string()) {
-            // We can't translate this call, it takes a string. So as a special hack we just make this `Unreachable`.
-            Terminator::Unreachable
+        } else if Some(f) == core_item(self.tcx, &["any", "TypeId", "of"]) {
+            // `TypeId::of` splits the `u128` returned by `type_id` into two `u64` with a shift,
+            // which MiniRust does not have. So we build the `TypeId { t: (u64, u64) }` ourselves.
+            let id = self.tcx.type_id_hash(substs_ref.type_at(0)).as_u128();
+            let ty = self.translate_ty(destination.ty(&self.body, self.tcx).ty, span);
+            let Type::Tuple { fields, .. } = ty else {
+                rs::span_bug!(span, "`TypeId` is not a struct");
+            };
+            let halves = [build::const_int((id >> 64) as u64), build::const_int(id as u64)];
+            let source = build::tuple(&[build::tuple(&halves, fields.index_at(0).1)], ty);
+            let destination = self.translate_place(destination, span);
+            self.assign_and_goto(destination, source, target.unwrap())
+        } else if is_panic_fn(&instance.to_string())
+            || Some(f) == self.tcx.lang_items().begin_panic_fn()
+        {
+            // We can't translate the panic message, so we call our own panic function instead.
+            // `begin_panic` is what `std::panic!` calls with a single argument before Rust 2021.
+            self.panic()
         } else {
             let abi = self
                 .cx
@@ -215,28 +492,129 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                 .unwrap();
             let conv = translate_calling_convention(abi.conv);
 
-            let args: List<_> = args
-                .iter()
-                .map(|x| {
-                    match &x.node {
-                        rs::Operand::Move(place) =>
-                            ArgumentExpr::InPlace(self.translate_place(place, x.span)),
-                        op => ArgumentExpr::ByValue(self.translate_operand(op, x.span)),
-                    }
-                })
-                .collect();
-
             Terminator::Call {
                 callee: build::fn_ptr_conv(self.cx.get_fn_name(instance).0.get_internal(), conv),
-                arguments: args,
-                ret: self.translate_place(&destination, span),
+                arguments: self.translate_call_args(func, args),
+                ret: self.translate_place(destination, span),
                 next_block: target.as_ref().map(|t| self.bb_name_map[t]),
             }
         }
     }
+
+    /// Functions with the "rust-call" ABI, like the methods of the `Fn*` traits, get their
+    /// arguments as a tuple in MIR, which is the last argument. But the callee (e.g. a closure
+    /// body) takes the fields of the tuple as separate arguments, so we untuple it.
+    fn translate_call_args(
+        &mut self,
+        func: &rs::Operand<'tcx>,
+        args: &[rs::Spanned<rs::Operand<'tcx>>],
+    ) -> List<ArgumentExpr> {
+        let abi = func.ty(&self.body, self.tcx).fn_sig(self.tcx).abi();
+        let (args, tupled) = match args.split_last() {
+            Some((tupled, args)) if abi == rs::SpecAbi::RustCall => (args, Some(tupled)),
+            _ => (args, None),
+        };
+        let mut arguments: List<ArgumentExpr> = args
+            .iter()
+            .map(|x| {
+                match &x.node {
+                    rs::Operand::Move(place) =>
+                        ArgumentExpr::InPlace(self.translate_place(place, x.span)),
+                    op => ArgumentExpr::ByValue(self.translate_operand(op, x.span)),
+                }
+            })
+            .collect();
+        if let Some(tupled) = tupled {
+            match &tupled.node {
+                rs::Operand::Move(place) | rs::Operand::Copy(place) => {
+                    let count = place.ty(&self.body, self.tcx).ty.tuple_fields().len();
+                    let place = self.translate_place(place, tupled.span);
+                    for field in 0..count {
+                        let field = build::field(place, field);
+                        arguments.push(ArgumentExpr::ByValue(build::load(field)));
+                    }
+                }
+                op => {
+                    let ValueExpr::Tuple(fields, _) = self.translate_operand(op, tupled.span)
+                    else {
+                        rs::span_bug!(tupled.span, "\"rust-call\" argument is not a tuple")
+                    };
+                    for field in fields {
+                        arguments.push(ArgumentExpr::ByValue(field));
+                    }
+                }
+            }
+        }
+        arguments
+    }
+
+    /// A terminator that is `Unreachable`, but keeps `next` as a successor: if nothing else jumps
+    /// there, the function would be ill-formed. So we switch on a constant that always picks an
+    /// unreachable block.
+    fn unreachable_before(&mut self, next: BbName) -> Terminator {
+        let unreachable_bb = self.fresh_bb_name();
+        let unreachable_blk =
+            BasicBlock { statements: List::new(), terminator: Terminator::Unreachable };
+        self.blocks.insert(unreachable_bb, unreachable_blk);
+        Terminator::Switch {
+            value: build::const_int::<u8>(0),
+            cases: [(Int::ZERO, unreachable_bb)].into_iter().collect(),
+            fallback: next,
+        }
+    }
+
+    /// A terminator that jumps to a fresh block which assigns `source` to `destination` and then
+    /// continues at `target`.
+    fn assign_and_goto(
+        &mut self,
+        destination: PlaceExpr,
+        source: ValueExpr,
+        target: rs::BasicBlock,
+    ) -> Terminator {
+        let assign = Statement::Assign { destination, source };
+        let assign_bb = self.fresh_bb_name();
+        let next = Terminator::Goto(self.bb_name_map[&target]);
+        let assign_blk = BasicBlock { statements: list![assign], terminator: next };
+        self.blocks.insert(assign_bb, assign_blk);
+        Terminator::Goto(assign_bb)
+    }
+
+    /// A terminator that panics, i.e. calls the panic function (see `Ctxt::get_panic_fn_name`).
+    fn panic(&mut self) -> Terminator {
+        let panic_fn = self.cx.get_panic_fn_name();
+        build::panic(panic_fn.0.get_internal())
+    }
 }
 
 // HACK to skip translating some functions we can't handle yet.
 fn is_panic_fn(name: &str) -> bool {
-    name == "core::panicking::panic" || name == "core::panicking::panic_nounwind"
+    // `panic!()` without arguments calls `panic_explicit`.
+    name == "core::panicking::panic"
+        || name == "core::panicking::panic_nounwind"
+        || name == "core::panicking::panic_explicit"
+}
+
+/// Finds the item at `path` in `core`, e.g. `["any", "TypeId", "of"]`, which can also be an
+/// associated item of a type. This is for functions that we treat specially but that have neither a
+/// lang item nor a diagnostic item.
+fn core_item(tcx: rs::TyCtxt<'_>, path: &[&str]) -> Option<rs::DefId> {
+    let core = *tcx.crates(()).iter().find(|&&krate| tcx.crate_name(krate) == rs::sym::core)?;
+    let mut def_id = core.as_def_id();
+    for name in path {
+        let name = rs::Symbol::intern(name);
+        def_id = match tcx.def_kind(def_id) {
+            rs::DefKind::Mod => {
+                let child = tcx.module_children(def_id).iter().find(|c| c.ident.name == name)?;
+                child.res.opt_def_id()?
+            }
+            _ => {
+                let impls = tcx.inherent_impls(def_id).ok()?;
+                let mut items = impls
+                    .iter()
+                    .flat_map(|&imp| tcx.associated_items(imp).filter_by_name_unhygienic(name));
+                items.next()?.def_id
+            }
+        };
+    }
+    Some(def_id)
 }
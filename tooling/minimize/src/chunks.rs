@@ -45,7 +45,7 @@ fn mark_used_bytes(ty: Type, markers: &mut [bool]) {
     match ty {
         Type::Int(int_ty) => mark_size(int_ty.size, markers),
         Type::Bool => mark_size(Size::from_bytes_const(1), markers),
-        Type::Ptr(_) => mark_size(DefaultTarget::PTR_SIZE, markers),
+        Type::Ptr(_) => mark_size(ty.size::<DefaultTarget>(), markers),
         Type::Tuple { fields, .. } =>
             for (offset, ty) in fields {
                 let offset = offset.bytes().try_to_usize().unwrap();
@@ -64,6 +64,7 @@ fn mark_used_bytes(ty: Type, markers: &mut [bool]) {
                 mark_used_bytes(elem, &mut markers[offset..]);
             }
         }
+        Type::Vector { elem, count } => mark_size(elem.size * count, markers),
         Type::Enum { variants, discriminator, .. } => {
             for Variant { ty, tagger } in variants.values() {
                 mark_used_bytes(ty, markers);
@@ -74,6 +75,7 @@ fn mark_used_bytes(ty: Type, markers: &mut [bool]) {
             }
             mark_discriminator(discriminator, markers);
         }
+        Type::Slice { .. } | Type::TraitObject => panic!("unions cannot have unsized fields"),
     }
 }
 
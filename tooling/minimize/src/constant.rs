@@ -1,6 +1,10 @@
 use crate::*;
 
 impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
+    /// This also covers promoted constants like `&42`: evaluating them yields a pointer to their
+    /// allocation, which `translate_alloc_id` turns into a global. The same goes for byte string
+    /// literals used as `&[u8; N]`, and slice constants like `b"abc" as &[u8]`, which are wide
+    /// pointers to such an allocation.
     pub fn translate_const(&mut self, c: &rs::mir::Const<'tcx>, span: rs::Span) -> ValueExpr {
         let val = match c.eval(self.tcx, rs::ParamEnv::reveal_all(), rs::DUMMY_SP) {
             Ok(val) => val,
@@ -36,24 +40,39 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                 let val = ecx.read_scalar(&val).unwrap().to_bool().unwrap();
                 ValueExpr::Constant(Constant::Bool(val), ty)
             }
-            Type::Ptr(ptr_ty) => {
-                if let PtrType::FnPtr(_) = ptr_ty {
-                    rs::span_bug!(span, "Function pointers are currently not supported")
-                }
-                let ptr = ecx.read_pointer(&val).unwrap();
-                let (prov, offset) = ptr.into_parts();
-                let c = match prov {
-                    None => {
-                        let addr: Int = offset.bytes_usize().into();
-                        Constant::PointerWithoutProvenance(addr)
+            // A wide pointer is a pair of the thin pointer and the metadata. This is how slice
+            // constants like `b"abc" as &[u8]` arrive (as `ConstValue::Slice`): the thin pointer
+            // points to the bytes, which `translate_alloc_id` turns into a global.
+            Type::Ptr(ptr_ty) if ptr_ty.is_wide() => {
+                let (ptr, meta) = ecx.read_immediate(&val).unwrap().to_scalar_pair();
+                let ptr = ptr.to_pointer(ecx).unwrap();
+                let ptr =
+                    ValueExpr::Constant(self.translate_ptr(ptr, ptr_ty, span), build::raw_ptr_ty());
+                let meta = match ptr_ty.meta_kind() {
+                    PointerMetaKind::ElementCount => {
+                        let len = meta.to_target_usize(ecx).unwrap();
+                        build::const_int_typed::<usize>(Int::from(len))
                     }
-                    Some(prov) => {
-                        let alloc_id = prov.alloc_id();
-                        let rel = self.translate_relocation(alloc_id, offset);
-                        Constant::GlobalPointer(rel)
+                    PointerMetaKind::VTablePointer => {
+                        let vtable = meta.to_pointer(ecx).unwrap();
+                        let Some(prov) = vtable.provenance else {
+                            rs::span_bug!(span, "vtable pointer without provenance")
+                        };
+                        let rs::GlobalAlloc::VTable(ty, trait_ref) =
+                            self.tcx.global_alloc(prov.alloc_id())
+                        else {
+                            rs::span_bug!(span, "wide pointer metadata is not a vtable")
+                        };
+                        let vtable = self.cx.get_vtable_name(ty, trait_ref);
+                        ValueExpr::Constant(Constant::VTablePointer(vtable), build::raw_ptr_ty())
                     }
+                    PointerMetaKind::None => unreachable!(),
                 };
-                ValueExpr::Constant(c, ty)
+                build::construct_wide_pointer(ptr, meta, ty)
+            }
+            Type::Ptr(ptr_ty) => {
+                let ptr = ecx.read_pointer(&val).unwrap();
+                ValueExpr::Constant(self.translate_ptr(ptr, ptr_ty, span), ty)
             }
             Type::Tuple { fields, .. } => {
                 let mut t: List<ValueExpr> = List::new();
@@ -97,6 +116,47 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
             }
             Type::Union { .. } =>
                 rs::span_bug!(span, "Constant Unions are currently not supported!"),
+            Type::Vector { .. } =>
+                rs::span_bug!(span, "Constant SIMD vectors are currently not supported!"),
+            Type::Slice { .. } | Type::TraitObject =>
+                rs::span_bug!(span, "constants of unsized type are not supported!"),
+        }
+    }
+
+    /// Translates the thin pointer `ptr`, which is (the thin part of) a pointer of type `ptr_ty`.
+    fn translate_ptr(
+        &mut self,
+        ptr: rs::Pointer<Option<rs::CtfeProvenance>>,
+        ptr_ty: PtrType,
+        span: rs::Span,
+    ) -> Constant {
+        let (prov, offset) = ptr.into_parts();
+        match prov {
+            None => {
+                let addr: Int = offset.bytes_usize().into();
+                Constant::PointerWithoutProvenance(addr)
+            }
+            Some(prov) if matches!(ptr_ty, PtrType::FnPtr(_)) => {
+                let rs::GlobalAlloc::Function(instance) = self.tcx.global_alloc(prov.alloc_id())
+                else {
+                    rs::span_bug!(span, "function pointer to non-function allocation")
+                };
+                if offset.bytes() != 0 {
+                    rs::span_bug!(span, "function pointer with non-zero offset")
+                }
+                Constant::FnPointer(self.cx.get_fn_name(instance))
+            }
+            Some(prov) => {
+                let alloc_id = prov.alloc_id();
+                if let rs::GlobalAlloc::VTable(..) = self.tcx.global_alloc(alloc_id) {
+                    rs::span_bug!(
+                        span,
+                        "pointers to vtables are only supported as wide pointer metadata"
+                    )
+                }
+                let rel = self.translate_relocation(alloc_id, offset);
+                Constant::GlobalPointer(rel)
+            }
         }
     }
 
@@ -108,7 +168,7 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
 
     // calls `translate_const_allocation` with the allocation of alloc_id,
     // and adds the alloc_id and its newly-created global to alloc_map.
-    fn translate_alloc_id(&mut self, alloc_id: rs::AllocId) -> GlobalName {
+    pub fn translate_alloc_id(&mut self, alloc_id: rs::AllocId) -> GlobalName {
         if let Some(x) = self.alloc_map.get(&alloc_id) {
             return *x;
         }
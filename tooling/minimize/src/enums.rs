@@ -11,16 +11,15 @@ impl<'tcx> Ctxt<'tcx> {
         span: rs::Span,
     ) -> Type {
         let layout = self.rs_layout_of(ty);
-        let size = translate_size(layout.size());
-        let align = translate_align(layout.align().abi);
-
-        let Type::Int(discriminant_ty) = self.translate_ty(ty.discriminant_ty(self.tcx), span)
-        else {
-            panic!("Discriminant type is not integer!")
-        };
-
-        let (variants, discriminator) = match layout.variants() {
+        match layout.variants() {
             rs::Variants::Single { index } => {
+                let size = translate_size(layout.size());
+                let align = translate_align(layout.align().abi);
+                let Type::Int(discriminant_ty) =
+                    self.translate_ty(ty.discriminant_ty(self.tcx), span)
+                else {
+                    panic!("Discriminant type is not integer!")
+                };
                 let fields = self.translate_adt_variant_fields(
                     layout.fields(),
                     adt_def.variant(*index),
@@ -29,14 +28,118 @@ impl<'tcx> Ctxt<'tcx> {
                 );
                 let variants = [(
                     Int::ZERO,
-                    Variant { ty: Type::Tuple { fields, size, align }, tagger: Map::new() },
+                    Variant {
+                        ty: Type::Tuple { fields, size, align, valid_range: None },
+                        tagger: Map::new(),
+                    },
                 )];
+                let variants = variants.into_iter().collect::<Map<Int, Variant>>();
                 let discriminator = Discriminator::Known(Int::ZERO);
-                (variants.into_iter().collect::<Map<Int, Variant>>(), discriminator)
+                Type::Enum { variants, discriminator, discriminant_ty, size, align }
+            }
+            rs::Variants::Multiple { variants, .. } => {
+                let variant_fields = adt_def
+                    .variants()
+                    .iter_enumerated()
+                    .map(|(variant_idx, variant_def)| {
+                        let fields = self.translate_adt_variant_fields(
+                            &variants[variant_idx].fields,
+                            variant_def,
+                            sref,
+                            span,
+                        );
+                        (variant_idx, fields)
+                    })
+                    .collect();
+                self.translate_tagged_variants(ty, variant_fields, span)
             }
-            rs::Variants::Multiple { tag, tag_encoding, tag_field, variants } => {
-                // compute the offset of the tag for the tagger and discriminator construction
-                let tag_offset: Offset = translate_size(layout.fields().offset(*tag_field));
+        }
+    }
+
+    /// Coroutines are enums in disguise: the layout picks a variant for every suspension point,
+    /// after the reserved "unresumed", "returned" and "panicked" states. These reserved variants
+    /// have no fields of their own. The captured upvars live outside the variants, in fields of
+    /// the coroutine itself.
+    pub fn translate_coroutine(
+        &self,
+        ty: rs::Ty<'tcx>,
+        def_id: rs::DefId,
+        args: rs::GenericArgsRef<'tcx>,
+        span: rs::Span,
+    ) -> Type {
+        let layout = self.rs_layout_of(ty);
+        let rs::Variants::Multiple { variants, .. } = layout.variants() else {
+            panic!("coroutine layout without variants: {ty}")
+        };
+        // MiniRust has no fields outside of the variants of an enum, so we add the upvars to every
+        // variant, after the variant's own fields. This keeps them intact no matter the state, and
+        // lets us project to them via the "unresumed" variant, which has no other fields.
+        let upvars: Vec<(Offset, Type)> = args
+            .as_coroutine()
+            .upvar_tys()
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| (translate_size(layout.fields().offset(i)), self.translate_ty(ty, span)))
+            .collect();
+        let variant_fields = args
+            .as_coroutine()
+            .state_tys(def_id, self.tcx)
+            .zip(variants.iter_enumerated())
+            .map(|(tys, (variant_idx, variant))| {
+                let fields = tys
+                    .enumerate()
+                    .map(|(i, ty)| {
+                        let ty = self.tcx.normalize_erasing_regions(rs::ParamEnv::reveal_all(), ty);
+                        (translate_size(variant.fields.offset(i)), self.translate_ty(ty, span))
+                    })
+                    .chain(upvars.iter().copied())
+                    .collect();
+                (variant_idx, fields)
+            })
+            .collect();
+        self.translate_tagged_variants(ty, variant_fields, span)
+    }
+
+    /// Translates an enum or coroutine with a tag, given the fields of each of its variants.
+    fn translate_tagged_variants(
+        &self,
+        ty: rs::Ty<'tcx>,
+        variant_fields: Vec<(rs::VariantIdx, Fields)>,
+        span: rs::Span,
+    ) -> Type {
+        let layout = self.rs_layout_of(ty);
+        let size = translate_size(layout.size());
+        let align = translate_align(layout.align().abi);
+
+        let Type::Int(discriminant_ty) = self.translate_ty(ty.discriminant_ty(self.tcx), span)
+        else {
+            panic!("Discriminant type is not integer!")
+        };
+
+        let (variants, discriminator) = match layout.variants() {
+            rs::Variants::Single { .. } => panic!("tagged translation of a single-variant layout"),
+            rs::Variants::Multiple { tag, tag_encoding, tag_field, .. } => {
+                // compute the offset of the tag for the tagger and discriminator construction.
+                // rustc guarantees that the tag is a field of the enum layout that fits into it,
+                // so this only fails for inconsistent layouts. Layouts always come from rustc,
+                // which is why there is no test feeding minimize such a layout.
+                if *tag_field >= layout.fields().count() {
+                    rs::span_bug!(
+                        span,
+                        "enum layout has tag field {tag_field}, but only {} fields",
+                        layout.fields().count()
+                    );
+                }
+                let rs_tag_offset = layout.fields().offset(*tag_field);
+                if rs_tag_offset + tag.size(&self.tcx) > layout.size() {
+                    rs::span_bug!(
+                        span,
+                        "enum tag at offset {} does not fit into the enum of size {}",
+                        rs_tag_offset.bytes(),
+                        layout.size().bytes()
+                    );
+                }
+                let tag_offset: Offset = translate_size(rs_tag_offset);
                 let Type::Int(tag_ty) =
                     self.translate_ty(tag.primitive().to_int_ty(self.tcx), span)
                 else {
@@ -46,23 +149,18 @@ impl<'tcx> Ctxt<'tcx> {
                 // translate the variants
                 let mut translated_variants = Map::new();
                 let mut discriminator_branches = Map::new();
-                for (variant_idx, variant_def) in adt_def.variants().iter_enumerated() {
-                    let fields = self.translate_adt_variant_fields(
-                        &variants[variant_idx].fields,
-                        &variant_def,
-                        sref,
-                        span,
-                    );
-                    let discr = adt_def.discriminant_for_variant(self.tcx, variant_idx);
-                    let discr_int = int_from_bits(discr.val, discriminant_ty);
+                for (variant_idx, fields) in variant_fields {
+                    let discr_int = self.discriminant_for_variant(ty, variant_idx, span);
                     match tag_encoding {
                         rs::TagEncoding::Direct => {
                             // direct tagging places the discriminant in the tag for all variants
                             let tagger = [(tag_offset, (tag_ty, discr_int))]
                                 .into_iter()
                                 .collect::<Map<Offset, (IntType, Int)>>();
-                            let variant =
-                                Variant { ty: Type::Tuple { fields, size, align }, tagger };
+                            let variant = Variant {
+                                ty: Type::Tuple { fields, size, align, valid_range: None },
+                                tagger,
+                            };
                             translated_variants.insert(discr_int, variant);
                             discriminator_branches.insert(
                                 (discr_int, discr_int + Int::ONE),
@@ -75,8 +173,9 @@ impl<'tcx> Ctxt<'tcx> {
                             niche_start,
                         } if *untagged_variant != variant_idx => {
                             // this is a tagged variant, meaning that it writes its tag and has a discriminator branch entry.
-                            let discr_int = int_from_bits(discr.val, tag_ty);
-                            let tag_int = (discr_int
+                            // Like rustc, the tag is computed from the variant *index*, which need
+                            // not be the discriminant, and it wraps around in the tag type.
+                            let tag_int = (Int::from(variant_idx.as_usize())
                                 - Int::from(niche_variants.start().as_usize())
                                 + Int::from(*niche_start))
                             .modulo(tag_ty.signed, tag_ty.size);
@@ -89,7 +188,10 @@ impl<'tcx> Ctxt<'tcx> {
                             );
                             translated_variants.insert(
                                 discr_int,
-                                Variant { ty: Type::Tuple { fields, size, align }, tagger },
+                                Variant {
+                                    ty: Type::Tuple { fields, size, align, valid_range: None },
+                                    tagger,
+                                },
                             );
                         }
                         rs::TagEncoding::Niche { .. } => {
@@ -98,7 +200,7 @@ impl<'tcx> Ctxt<'tcx> {
                             translated_variants.insert(
                                 discr_int,
                                 Variant {
-                                    ty: Type::Tuple { fields, size, align },
+                                    ty: Type::Tuple { fields, size, align, valid_range: None },
                                     tagger: Map::new(),
                                 },
                             );
@@ -183,15 +285,14 @@ impl<'tcx> Ctxt<'tcx> {
         variant_idx: rs::VariantIdx,
         span: rs::Span,
     ) -> Int {
-        let rs::TyKind::Adt(adt_def, _) = ty.kind() else {
-            panic!("Getting discriminant for a variant of a non-enum type!")
-        };
-        assert!(adt_def.is_enum());
         let Type::Int(discriminant_ty) = self.translate_ty(ty.discriminant_ty(self.tcx), span)
         else {
             panic!("Discriminant type is not integer!")
         };
-        let discriminant = adt_def.discriminant_for_variant(self.tcx, variant_idx);
+        // This handles both enums and coroutines.
+        let Some(discriminant) = ty.discriminant_for_variant(self.tcx, variant_idx) else {
+            panic!("Getting discriminant for a variant of a non-enum type!")
+        };
         int_from_bits(discriminant.val, discriminant_ty)
     }
 }
@@ -13,6 +13,9 @@ pub struct FnCtxt<'cx, 'tcx> {
 
     pub cx: &'cx mut Ctxt<'tcx>,
 
+    /// the name of the function we are translating.
+    pub fn_name: FnName,
+
     /// associate names for each mir Local.
     pub local_name_map: HashMap<rs::Local, LocalName>,
 
@@ -49,11 +52,13 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
             .fn_abi_of_instance(rs::ParamEnv::reveal_all().and((instance, rs::List::empty())))
             .unwrap();
         let locals_smir = smir::stable(&body).locals().to_vec();
+        let fn_name = cx.fn_name_map[&instance];
 
         FnCtxt {
             body,
             abi,
             cx,
+            fn_name,
             local_name_map: Default::default(),
             bb_name_map: Default::default(),
             locals: Default::default(),
@@ -73,7 +78,19 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
     /// Any fn calls occuring during this translation will be added to the `FnNameMap`.
     pub fn translate(mut self) -> Function {
         // associate names for each mir BB.
-        for bb_id in self.body.basic_blocks.indices() {
+        let bbs: Vec<(rs::BasicBlock, bool)> = self
+            .body
+            .basic_blocks
+            .iter_enumerated()
+            .map(|(bb_id, bb_data)| (bb_id, bb_data.is_cleanup))
+            .collect();
+        for (bb_id, is_cleanup) in bbs {
+            // Cleanup blocks are only reached by unwinding, which never happens in MiniRust since
+            // panics abort. Our crate is built with `-Cpanic=abort` and has none, but the standard
+            // library is not, so e.g. `Box::new` has cleanup blocks ending in `UnwindResume`.
+            if is_cleanup {
+                continue;
+            }
             let bb_name = self.fresh_bb_name();
             self.bb_name_map.insert(bb_id, bb_name);
         }
@@ -94,19 +111,33 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
         // the number of locals which are implicitly storage live.
         let free_argc = self.body.arg_count + 1;
 
+        let spread = self.translate_spread_arg();
+
         // add init basic block
         let init_bb = self.fresh_bb_name();
 
         // this block allocates all "always_storage_live_locals",
         // except for those which are implicitly storage live in Minirust;
         // like the return local and function args.
+        let mut statements: List<Statement> = rs::always_storage_live_locals(&self.body)
+            .iter()
+            .map(|loc| self.local_name_map[&loc])
+            .filter(|LocalName(i)| i.get_internal() as usize >= free_argc)
+            .map(Statement::StorageLive)
+            .collect();
+        // The spread argument is not an argument in MiniRust, so we have to
+        // allocate it and assemble it from the arguments that replace it.
+        if let Some((spread, fields)) = spread {
+            let ty = self.locals.get(spread).unwrap();
+            let fields = fields.iter().map(|l| build::load(PlaceExpr::Local(l))).collect();
+            statements.push(Statement::StorageLive(spread));
+            statements.push(Statement::Assign {
+                destination: PlaceExpr::Local(spread),
+                source: ValueExpr::Tuple(fields, ty),
+            });
+        }
         let init_blk = BasicBlock {
-            statements: rs::always_storage_live_locals(&self.body)
-                .iter()
-                .map(|loc| self.local_name_map[&loc])
-                .filter(|LocalName(i)| i.get_internal() as usize >= free_argc)
-                .map(Statement::StorageLive)
-                .collect(),
+            statements,
             terminator: Terminator::Goto(self.bb_name_map[&rs::mir::START_BLOCK]),
         };
         self.blocks.insert(init_bb, init_blk);
@@ -121,12 +152,7 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
         // - https://doc.rust-lang.org/stable/nightly-rustc/rustc_middle/mir/struct.Body.html
         let ret = LocalName(Name::from_internal(0));
 
-        let mut args = List::default();
-        for i in 0..self.body.arg_count {
-            let i = i + 1; // this starts counting with 1, as id 0 is the return value of the function.
-            let local_name = LocalName(Name::from_internal(i as _));
-            args.push(local_name);
-        }
+        let args = self.args(spread);
 
         let f = Function {
             locals: self.locals,
@@ -139,4 +165,69 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
 
         f
     }
+
+    /// Functions with the "rust-call" ABI (like the `Fn*` trait methods) can have a "spread
+    /// argument": in MIR, it is a tuple, but the caller passes its fields as separate arguments
+    /// (see `translate_call_args`). If there is one, this adds locals for the fields, and returns
+    /// the spread argument together with them.
+    fn translate_spread_arg(&mut self) -> Option<(LocalName, List<LocalName>)> {
+        let spread = self.local_name_map[&self.body.spread_arg?];
+        let Type::Tuple { fields, .. } = self.locals.get(spread).unwrap() else {
+            panic!("the spread argument is not a tuple")
+        };
+        // The names after those of the MIR locals are free.
+        let first = self.body.local_decls.len();
+        let names: List<LocalName> = (0..fields.len().try_to_usize().unwrap())
+            .map(|i| LocalName(Name::from_internal((first + i) as _)))
+            .collect();
+        for (name, (_offset, ty)) in names.iter().zip(fields) {
+            self.locals.insert(name, ty);
+        }
+        Some((spread, names))
+    }
+
+    /// The arguments of the translated function, where the spread argument (if any) is
+    /// replaced by the locals for its fields.
+    fn args(&self, spread: Option<(LocalName, List<LocalName>)>) -> List<LocalName> {
+        let mut args = List::default();
+        for i in 0..self.body.arg_count {
+            let i = i + 1; // this starts counting with 1, as id 0 is the return value of the function.
+            let local_name = LocalName(Name::from_internal(i as _));
+            match spread {
+                Some((spread, fields)) if spread == local_name =>
+                    for field in fields {
+                        args.push(field);
+                    },
+                _ => args.push(local_name),
+            }
+        }
+        args
+    }
+
+    /// Builds a function with the signature of this body which immediately executes `Unreachable`.
+    /// This replaces functions that could not be translated.
+    pub fn translate_stub(mut self) -> Function {
+        for i in 0..=self.body.arg_count {
+            let local_decl = &self.body.local_decls[rs::Local::from_usize(i)];
+            let span = local_decl.source_info.span;
+            let local_name = LocalName(Name::from_internal(i as _));
+            self.locals.insert(local_name, self.translate_ty(local_decl.ty, span));
+            self.local_name_map.insert(rs::Local::from_usize(i), local_name);
+        }
+        let spread = self.translate_spread_arg();
+        let args = self.args(spread);
+
+        let bb = self.fresh_bb_name();
+        let blk = BasicBlock { statements: List::new(), terminator: Terminator::Unreachable };
+        self.blocks.insert(bb, blk);
+
+        Function {
+            locals: self.locals,
+            args,
+            ret: LocalName(Name::from_internal(0)),
+            blocks: self.blocks,
+            start: bb,
+            calling_convention: translate_calling_convention(self.abi.conv),
+        }
+    }
 }
@@ -19,16 +19,18 @@ extern crate rustc_target;
 extern crate stable_mir;
 
 mod rs {
+    pub use crate::span_bug;
     pub use rustc_const_eval::const_eval::{mk_eval_cx_for_const_val, CompileTimeInterpreter};
     pub use rustc_const_eval::interpret::{InterpCx, OpTy};
+    pub use rustc_hir::{def::DefKind, def_id::DefId, LangItem};
     pub use rustc_middle::mir::{self, interpret::*, *};
-    pub use rustc_middle::span_bug;
     pub use rustc_middle::ty::*;
     pub use rustc_mir_dataflow::storage::always_storage_live_locals;
     pub use rustc_span::source_map::Spanned;
-    pub use rustc_span::{Span, DUMMY_SP};
+    pub use rustc_span::{sym, Span, Symbol, DUMMY_SP};
     pub use rustc_target::abi::{self, call::*, Align, FieldIdx, Layout, Size};
     pub use rustc_target::abi::{FieldsShape, TagEncoding, VariantIdx, Variants};
+    pub use rustc_target::spec::abi::Abi as SpecAbi;
 }
 // Traits
 pub use rustc_middle::ty::layout::IntegerExt as _;
@@ -51,7 +53,7 @@ pub use minirust_rs::prelude::NdResult;
 pub use minirust_rs::prelude::*;
 
 pub use miniutil::build::{self, TypeConv as _};
-pub use miniutil::fmt::dump_program;
+pub use miniutil::fmt::{dump_program, fmt_fn_name, fmt_program_filtered};
 pub use miniutil::run::*;
 pub use miniutil::DefaultTarget;
 
@@ -84,6 +86,8 @@ use enums::int_from_bits;
 
 use std::collections::HashMap;
 use std::env::Args;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Arc;
 
 pub const DEFAULT_ARGS: &[&str] = &[
     "--cfg=miri",
@@ -95,33 +99,118 @@ pub const DEFAULT_ARGS: &[&str] = &[
     "-Cpanic=abort",
 ];
 
+/// The panic payload of `rs::span_bug!`.
+pub struct Unsupported {
+    pub span: rs::Span,
+    pub msg: String,
+}
+
+/// Reports a construct that minimize cannot translate.
+/// Unlike `rustc_middle::span_bug!` this does not ICE: `Ctxt::translate` catches the panic,
+/// replaces the function being translated with an `Unreachable` stub, and reports the error
+/// once translation is done.
+#[macro_export]
+macro_rules! span_bug {
+    ($span:expr, $($tt:tt)*) => {
+        std::panic::panic_any($crate::Unsupported { span: $span, msg: std::format!($($tt)*) })
+    };
+}
+
+/// Runs `f`, catching panics. `Unsupported` panics are not printed, since `Ctxt::translate`
+/// reports those itself; other panics are printed by the previous panic hook as usual.
+pub fn catch_unsupported<R>(f: impl FnOnce() -> R) -> std::thread::Result<R> {
+    let default_hook = Arc::new(std::panic::take_hook());
+    let hook = default_hook.clone();
+    std::panic::set_hook(Box::new(move |info| {
+        if !info.payload().is::<Unsupported>() {
+            hook(info)
+        }
+    }));
+    let res = catch_unwind(AssertUnwindSafe(f));
+    // Dropping our hook releases its reference to the previous hook, so we can reinstall that.
+    drop(std::panic::take_hook());
+    let Ok(default_hook) = Arc::try_unwrap(default_hook) else { unreachable!() };
+    std::panic::set_hook(default_hook);
+    res
+}
+
 fn show_error(msg: &impl std::fmt::Display) -> ! {
     eprintln!("fatal error: {msg}");
     std::process::exit(101) // exit code needed to make ui_test happy
 }
 
+/// Prints a fatal error built from the format arguments and exits.
+#[macro_export]
 macro_rules! show_error {
-    ($($tt:tt)*) => { crate::show_error(&format_args!($($tt)*)) };
+    ($($tt:tt)*) => { $crate::show_error(&format_args!($($tt)*)) };
 }
 
 fn main() {
     let (minimize_args, rustc_args) = split_args(std::env::args());
+    // `--minimize-dump` prints the program instead of running it,
+    // `--minimize-dump-to=<file>` writes it to a file and then runs it.
     let dump = minimize_args.iter().any(|x| x == "--minimize-dump");
-    get_mini(rustc_args, |_tcx, prog| {
-        if dump {
-            dump_program(prog);
-        } else {
-            match run_program(prog) {
+    let dump_to = minimize_args.iter().find_map(|x| x.strip_prefix("--minimize-dump-to="));
+    // `--minimize-dump-fn=<name>` (may be repeated) restricts the dump to the given functions,
+    // named like in the dump itself, e.g. `f3`.
+    let dump_fns: Vec<&str> =
+        minimize_args.iter().filter_map(|x| x.strip_prefix("--minimize-dump-fn=")).collect();
+    let dump_fns = &dump_fns;
+    // `--minimize-ub-span` makes UB reports point to the Rust code that caused the UB.
+    let ub_span = minimize_args.iter().any(|x| x == "--minimize-ub-span");
+    // `--minimize-asm=noop` makes inline assembly do nothing instead of being UB when reached.
+    let asm_mode = match minimize_args.iter().find_map(|x| x.strip_prefix("--minimize-asm=")) {
+        None | Some("ub") => AsmMode::Ub,
+        Some("noop") => AsmMode::Noop,
+        Some(mode) =>
+            show_error!("unknown `--minimize-asm` mode `{mode}`, expected `ub` or `noop`"),
+    };
+    // `--minimize-function=<path>` starts with the given function instead of `main`, calling it
+    // with zeroed arguments and printing its return value, e.g. `--minimize-function=foo::bar`.
+    let entry_path =
+        minimize_args.iter().find_map(|x| x.strip_prefix("--minimize-function=")).map(String::from);
+    get_mini(rustc_args, asm_mode, entry_path, |tcx, prog, spans| {
+        if dump || dump_to.is_some() {
+            let filter = |fn_name: FnName| {
+                dump_fns.is_empty() || dump_fns.contains(&fmt_fn_name(fn_name).as_str())
+            };
+            let s = fmt_program_filtered(prog, filter);
+            match dump_to {
+                Some(path) =>
+                    std::fs::write(path, s)
+                        .unwrap_or_else(|err| show_error!("could not write {path}: {err}")),
+                None => println!("{s}"),
+            }
+        }
+        if !dump {
+            let (info, location) = run_program_with_location(prog);
+            match info {
                 // We can't use tcx.dcx().fatal due to <https://github.com/oli-obk/ui_test/issues/226>
                 TerminationInfo::IllFormed(err) =>
                     show_error!(
                         "program not well-formed (this is a bug in minimize):\n    {}",
                         err.get_internal()
                     ),
-                TerminationInfo::MachineStop => { /* silent exit. */ }
-                TerminationInfo::Ub(err) => show_error!("UB: {}", err.get_internal()),
+                TerminationInfo::MachineStop(code) if code == Int::ZERO => { /* silent exit. */ }
+                TerminationInfo::MachineStop(code) =>
+                    match code.try_to_u8() {
+                        Some(code) => std::process::exit(code.into()),
+                        None => show_error!("program exited with out-of-range exit code {code}"),
+                    },
+                TerminationInfo::Ub(err) =>
+                    match location.and_then(|loc| spans.get(&loc)).filter(|_| ub_span) {
+                        Some(span) => {
+                            let span = tcx.sess.source_map().span_to_diagnostic_string(*span);
+                            show_error!("UB: {}\n  at {span}", err.get_internal())
+                        }
+                        None => show_error!("UB: {}", err.get_internal()),
+                    },
                 TerminationInfo::Deadlock => show_error!("program dead-locked"),
                 TerminationInfo::MemoryLeak => show_error!("program leaked memory"),
+                TerminationInfo::Abort => show_error!("program aborted"),
+                TerminationInfo::DataRace => show_error!("UB: data race"),
+                TerminationInfo::StepLimit => show_error!("program exceeded the step limit"),
+                TerminationInfo::StackOverflow => show_error!("program overflowed its stack"),
             }
         }
     });
@@ -141,16 +230,25 @@ fn split_args(args: Args) -> (Vec<String>, Vec<String>) {
     (minimize_args, rustc_args)
 }
 
-fn get_mini(mut args: Vec<String>, callback: impl FnOnce(rs::TyCtxt<'_>, Program) + Send + Copy) {
+fn get_mini(
+    mut args: Vec<String>,
+    asm_mode: AsmMode,
+    entry_path: Option<String>,
+    callback: impl FnOnce(rs::TyCtxt<'_>, Program, &SpanMap) + Send + Copy,
+) {
     args.splice(1..1, DEFAULT_ARGS.iter().map(ToString::to_string));
-    rustc_driver::RunCompiler::new(&args, &mut Cb { callback }).run().unwrap();
+    rustc_driver::RunCompiler::new(&args, &mut Cb { asm_mode, entry_path, callback })
+        .run()
+        .unwrap();
 }
 
-struct Cb<F: FnOnce(rs::TyCtxt<'_>, Program) + Send + Copy> {
+struct Cb<F: FnOnce(rs::TyCtxt<'_>, Program, &SpanMap) + Send + Copy> {
+    asm_mode: AsmMode,
+    entry_path: Option<String>,
     callback: F,
 }
 
-impl<F: FnOnce(rs::TyCtxt<'_>, Program) + Send + Copy> rustc_driver::Callbacks for Cb<F> {
+impl<F: FnOnce(rs::TyCtxt<'_>, Program, &SpanMap) + Send + Copy> rustc_driver::Callbacks for Cb<F> {
     fn after_analysis<'tcx>(
         &mut self,
         _compiler: &rustc_interface::interface::Compiler,
@@ -159,8 +257,11 @@ impl<F: FnOnce(rs::TyCtxt<'_>, Program) + Send + Copy> rustc_driver::Callbacks f
         queries.global_ctxt().unwrap().enter(|tcx| {
             // StableMIR can only be used inside a `run` call, to guarantee its context is properly
             // initialized. Calls to StableMIR functions will panic if done outside a run.
-            let prog = smir::run(tcx, || Ctxt::new(tcx).translate()).unwrap();
-            (self.callback)(tcx, prog);
+            let asm_mode = self.asm_mode;
+            let entry_path = self.entry_path.clone();
+            let (prog, spans) =
+                smir::run(tcx, || Ctxt::new(tcx, asm_mode, entry_path).translate()).unwrap();
+            (self.callback)(tcx, prog, &spans);
         });
 
         rustc_driver::Compilation::Stop
@@ -1,5 +1,17 @@
 use crate::*;
 
+/// Maps program locations to Rust spans.
+pub type SpanMap = HashMap<Location, rs::Span>;
+
+/// How to translate inline assembly, which MiniRust cannot model.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AsmMode {
+    /// Reaching inline assembly is UB.
+    Ub,
+    /// Inline assembly does nothing and just continues with its fallthrough block.
+    Noop,
+}
+
 pub struct Ctxt<'tcx> {
     pub tcx: rs::TyCtxt<'tcx>,
 
@@ -12,10 +24,33 @@ pub struct Ctxt<'tcx> {
     pub globals: Map<GlobalName, Global>,
 
     pub functions: Map<FnName, Function>,
+
+    /// Stores which vtable of a type for a trait is which VTableName.
+    pub vtable_map: HashMap<(rs::Ty<'tcx>, Option<rs::PolyExistentialTraitRef<'tcx>>), VTableName>,
+
+    pub vtables: Map<VTableName, VTable>,
+
+    /// The span of the Rust statement or terminator that each MiniRust statement or terminator
+    /// was translated from.
+    pub spans: SpanMap,
+
+    /// What to do with inline assembly.
+    pub asm_mode: AsmMode,
+
+    /// The path of the function to start with instead of `main`, if any.
+    /// It is called with zeroed arguments, and its return value is printed.
+    pub entry_path: Option<String>,
+
+    /// Functions containing inline assembly, with their paths.
+    pub unmodeled_asm: Vec<(FnName, String)>,
+
+    /// Functions that could not be translated, and why.
+    /// They are replaced by `Unreachable` stubs.
+    pub translation_errors: Vec<String>,
 }
 
 impl<'tcx> Ctxt<'tcx> {
-    pub fn new(tcx: rs::TyCtxt<'tcx>) -> Self {
+    pub fn new(tcx: rs::TyCtxt<'tcx>, asm_mode: AsmMode, entry_path: Option<String>) -> Self {
         // Ensure consistency with the DefaultTarget
         let dl = tcx.data_layout();
         assert_eq!(DefaultTarget::PTR_SIZE, translate_size(dl.pointer_size));
@@ -47,11 +82,24 @@ impl<'tcx> Ctxt<'tcx> {
             alloc_map: Default::default(),
             globals: Default::default(),
             functions: Default::default(),
+            vtable_map: Default::default(),
+            vtables: Default::default(),
+            spans: Default::default(),
+            asm_mode,
+            entry_path,
+            unmodeled_asm: Default::default(),
+            translation_errors: Default::default(),
         }
     }
 
-    pub fn translate(mut self) -> Program {
-        let (entry, _ty) = self.tcx.entry_fn(()).unwrap();
+    pub fn translate(mut self) -> (Program, SpanMap) {
+        let (entry, start_fn) = match self.entry_path.clone() {
+            None => (self.tcx.entry_fn(()).unwrap().0, mk_start_fn(0)),
+            Some(path) => {
+                let entry = self.find_fn(&path);
+                (entry, self.mk_zeroed_args_start_fn(entry, &path))
+            }
+        };
         let entry_instance = rs::Instance::mono(self.tcx, entry);
         let entry_name = FnName(Name::from_internal(0));
 
@@ -63,19 +111,51 @@ impl<'tcx> Ctxt<'tcx> {
             self.fn_name_map.values().find(|k| !self.functions.contains_key(**k)).copied()
         {
             let instance =
-                self.fn_name_map.iter().find(|(_, f)| **f == fn_name).map(|(r, _)| r).unwrap();
+                *self.fn_name_map.iter().find(|(_, f)| **f == fn_name).map(|(r, _)| r).unwrap();
 
-            let f = FnCtxt::new(*instance, &mut self).translate();
+            if instance.def_id() == self.tcx.require_lang_item(rs::LangItem::Panic, None) {
+                // All panics call this function, see `get_panic_fn_name`.
+                self.functions.insert(fn_name, build::panic_fn());
+                continue;
+            }
+
+            // An unsupported construct should not stop us from translating the rest of the crate.
+            let f = catch_unsupported(|| FnCtxt::new(instance, &mut self).translate())
+                .unwrap_or_else(|payload| {
+                    let reason = self.panic_reason(&*payload);
+                    self.spans.retain(|(f, _, _), _| *f != fn_name);
+                    self.unmodeled_asm.retain(|(f, _)| *f != fn_name);
+                    let path = self.tcx.def_path_str(instance.def_id());
+                    self.translation_errors.push(format!(
+                        "could not translate `{path}`, using an `Unreachable` stub: {reason}"
+                    ));
+                    // Try to keep the signature of the function, so that calling it is fine.
+                    catch_unsupported(|| FnCtxt::new(instance, &mut self).translate_stub())
+                        .unwrap_or_else(|_| mk_unreachable_fn())
+                });
             self.functions.insert(fn_name, f);
         }
 
+        for (_, path) in &self.unmodeled_asm {
+            eprintln!("warning: `{path}` contains inline assembly, which is not modeled");
+        }
+        for err in &self.translation_errors {
+            eprintln!("warning: {err}");
+        }
+
         let number_of_fns = self.fn_name_map.len();
 
         // add a `start` function, which calls `entry`.
         let start = FnName(Name::from_internal(number_of_fns as _));
-        self.functions.insert(start, mk_start_fn(0));
+        self.functions.insert(start, start_fn);
 
-        Program { start, functions: self.functions, globals: self.globals }
+        let prog = Program {
+            start,
+            functions: self.functions,
+            globals: self.globals,
+            vtables: self.vtables,
+        };
+        (prog, self.spans)
     }
 
     // Returns FnName associated with some key. If it does not exist it creates a new one.
@@ -86,15 +166,175 @@ impl<'tcx> Ctxt<'tcx> {
         *self.fn_name_map.entry(key).or_insert_with(|| FnName(Name::from_internal(len as _)))
     }
 
+    /// Returns the VTableName of the vtable of `ty` for `trait_ref`, creating the vtable if needed.
+    /// The methods in the vtable are named by their index in the Rust vtable.
+    pub fn get_vtable_name(
+        &mut self,
+        ty: rs::Ty<'tcx>,
+        trait_ref: Option<rs::PolyExistentialTraitRef<'tcx>>,
+    ) -> VTableName {
+        if let Some(name) = self.vtable_map.get(&(ty, trait_ref)) {
+            return *name;
+        }
+
+        let name = VTableName(Name::from_internal(self.vtable_map.len() as _));
+        self.vtable_map.insert((ty, trait_ref), name);
+
+        // The vtable entries of a trait include the methods of its supertraits.
+        let mut methods = Map::new();
+        if let Some(trait_ref) = trait_ref {
+            let trait_ref = trait_ref.with_self_ty(self.tcx, ty);
+            for (idx, entry) in self.tcx.vtable_entries(trait_ref).iter().enumerate() {
+                match entry {
+                    // Shims, like `FnOnce::call_once` for closures, have no MIR body that we
+                    // could translate. We leave them out, so calling them through the vtable is UB.
+                    rs::VtblEntry::Method(instance)
+                        if matches!(instance.def, rs::InstanceDef::Item(_)) =>
+                    {
+                        let method = TraitMethodName(Name::from_internal(idx as _));
+                        methods.insert(method, self.get_fn_name(*instance));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let layout = self.layout_of(ty);
+        self.vtables.insert(name, VTable { size: layout.size, align: layout.align, methods });
+        name
+    }
+
+    /// Returns the FnName of the function that panics call.
+    /// We use `core::panicking::panic` for this, but replace its body by `build::panic_fn`.
+    /// That function takes no arguments, so we do not have to translate the panic message.
+    pub fn get_panic_fn_name(&mut self) -> FnName {
+        let panic_fn = self.tcx.require_lang_item(rs::LangItem::Panic, None);
+        self.get_fn_name(rs::Instance::mono(self.tcx, panic_fn))
+    }
+
     pub fn get_fn_name_smir(&mut self, key: smir::Instance) -> FnName {
         self.get_fn_name(smir::internal(self.tcx, key))
     }
 
+    fn panic_reason(&self, payload: &(dyn std::any::Any + Send)) -> String {
+        if let Some(Unsupported { span, msg }) = payload.downcast_ref::<Unsupported>() {
+            let loc = self.tcx.sess.source_map().span_to_diagnostic_string(*span);
+            format!("{loc}: {msg}")
+        } else if let Some(msg) = payload.downcast_ref::<&str>() {
+            msg.to_string()
+        } else if let Some(msg) = payload.downcast_ref::<String>() {
+            msg.clone()
+        } else {
+            "translation panicked".to_string()
+        }
+    }
+
+    /// Finds the non-generic function of the local crate with the given path.
+    fn find_fn(&self, path: &str) -> rs::DefId {
+        let def_id = self.tcx.hir().body_owners().map(|id| id.to_def_id()).find(|def_id| {
+            matches!(self.tcx.def_kind(*def_id), rs::DefKind::Fn | rs::DefKind::AssocFn)
+                && self.tcx.def_path_str(*def_id) == path
+        });
+        let Some(def_id) = def_id else {
+            crate::show_error!("there is no function `{path}` in this crate")
+        };
+        if self.tcx.generics_of(def_id).requires_monomorphization(self.tcx) {
+            crate::show_error!("cannot start with `{path}`, it is generic")
+        }
+        def_id
+    }
+
+    /// A `start` function that calls `entry` with all arguments zeroed, then prints the
+    /// return value and exits.
+    fn mk_zeroed_args_start_fn(&self, entry: rs::DefId, path: &str) -> Function {
+        let span = self.tcx.def_span(entry);
+        let sig = self.tcx.fn_sig(entry).instantiate_identity();
+        let sig = self.tcx.normalize_erasing_late_bound_regions(rs::ParamEnv::reveal_all(), sig);
+
+        let arguments = sig
+            .inputs()
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| {
+                let zero_valid = self
+                    .tcx
+                    .check_validity_requirement((
+                        rs::layout::ValidityRequirement::Zero,
+                        rs::ParamEnv::reveal_all().and(*ty),
+                    ))
+                    .unwrap();
+                if !zero_valid {
+                    crate::show_error!(
+                        "cannot start with `{path}`: argument {i} has type `{ty}`, \
+                         for which zero is not a valid value"
+                    )
+                }
+                let ty = self.translate_ty(*ty, span);
+                let size = ty.size::<DefaultTarget>().bytes();
+                let zeros = build::array_repeat(build::const_int(0u8), size, <u8>::get_type());
+                build::by_value(build::transmute(zeros, ty))
+            })
+            .collect();
+
+        let ret_ty = sig.output();
+        let print_ret = match ret_ty.kind() {
+            rs::TyKind::Bool | rs::TyKind::Int(_) | rs::TyKind::Uint(_) => true,
+            rs::TyKind::Tuple(ts) if ts.is_empty() => false,
+            rs::TyKind::Never => false,
+            _ =>
+                crate::show_error!(
+                    "cannot start with `{path}`: its return type `{ret_ty}` cannot be printed"
+                ),
+        };
+
+        let b0 = build::block(
+            &[build::storage_live(0)],
+            Terminator::Call {
+                callee: build::fn_ptr_conv(0, CallingConvention::Rust),
+                arguments,
+                ret: build::local(0),
+                next_block: Some(BbName(Name::from_internal(1))),
+            },
+        );
+        let exit = build::block(&[], build::exit());
+        let blocks = if print_ret {
+            let print = build::block(&[], build::print(build::load(build::local(0)), 2));
+            vec![b0, print, exit]
+        } else {
+            vec![b0, exit]
+        };
+        let locals = [self.translate_ty(ret_ty, span)];
+        build::function(build::Ret::No, 0, &locals, &blocks)
+    }
+
     pub fn rs_layout_of(&self, ty: rs::Ty<'tcx>) -> rs::Layout<'tcx> {
         self.tcx.layout_of(rs::ParamEnv::reveal_all().and(ty)).unwrap().layout
     }
 }
 
+/// A function that takes no arguments and immediately executes `Unreachable`.
+fn mk_unreachable_fn() -> Function {
+    let b0_name = BbName(Name::from_internal(0));
+    let l0_name = LocalName(Name::from_internal(0));
+
+    let b0 = BasicBlock { statements: List::new(), terminator: Terminator::Unreachable };
+
+    let mut blocks = Map::new();
+    blocks.insert(b0_name, b0);
+
+    let mut locals = Map::new();
+    locals.insert(l0_name, <()>::get_type());
+
+    Function {
+        locals,
+        args: List::new(),
+        ret: l0_name,
+        blocks,
+        start: b0_name,
+        calling_convention: CallingConvention::Rust,
+    }
+}
+
 fn mk_start_fn(entry: u32) -> Function {
     let b0_name = BbName(Name::from_internal(0));
     let b1_name = BbName(Name::from_internal(1));
@@ -41,6 +41,14 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                     (Eq, Type::Int(_)) => BinOp::IntRel(IntRel::Eq),
                     (Ne, Type::Int(_)) => BinOp::IntRel(IntRel::Ne),
 
+                    // all pointer relations
+                    (Lt, Type::Ptr(_)) => BinOp::PtrRel(IntRel::Lt),
+                    (Le, Type::Ptr(_)) => BinOp::PtrRel(IntRel::Le),
+                    (Gt, Type::Ptr(_)) => BinOp::PtrRel(IntRel::Gt),
+                    (Ge, Type::Ptr(_)) => BinOp::PtrRel(IntRel::Ge),
+                    (Eq, Type::Ptr(_)) => BinOp::PtrRel(IntRel::Eq),
+                    (Ne, Type::Ptr(_)) => BinOp::PtrRel(IntRel::Ne),
+
                     // all bool ops
                     (BitAnd, Type::Bool) => BinOp::Bool(BoolBinOp::BitAnd),
                     (BitOr, Type::Bool) => BinOp::Bool(BoolBinOp::BitOr),
@@ -73,19 +81,31 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
 
                 let place = self.translate_place_smir(place, span);
                 let target = GcCow::new(place);
-                let mutbl = translate_mutbl_smir(bkind.to_mutable_lossy());
+                let mutbl = match bkind {
+                    smir::BorrowKind::Shared => Mutability::Immutable,
+                    // This includes two-phase borrows and closure captures.
+                    smir::BorrowKind::Mut { .. } => Mutability::Mutable,
+                    // Fake borrows are removed before runtime MIR, treat them like shared ones.
+                    smir::BorrowKind::Fake(_) => Mutability::Immutable,
+                };
+                let two_phase = matches!(
+                    bkind,
+                    smir::BorrowKind::Mut { kind: smir::MutBorrowKind::TwoPhaseBorrow }
+                );
 
                 let ptr_ty = PtrType::Ref { mutbl, pointee };
 
-                ValueExpr::AddrOf { target, ptr_ty }
+                ValueExpr::AddrOf { target, ptr_ty, two_phase }
             }
             smir::Rvalue::AddressOf(_mutbl, place) => {
                 let place = self.translate_place_smir(place, span);
                 let target = GcCow::new(place);
 
-                let ptr_ty = PtrType::Raw;
+                // The pointer carries metadata if the place is unsized.
+                let ty = rv.ty(&self.locals_smir).unwrap();
+                let Type::Ptr(ptr_ty) = self.translate_ty_smir(ty, span) else { unreachable!() };
 
-                ValueExpr::AddrOf { target, ptr_ty }
+                ValueExpr::AddrOf { target, ptr_ty, two_phase: false }
             }
             smir::Rvalue::Aggregate(agg, operands) => {
                 let ty = rv.ty(&self.locals_smir).unwrap();
@@ -109,14 +129,17 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                         ValueExpr::Tuple(ops, ty)
                     }
                     Type::Enum { variants, .. } => {
-                        let smir::AggregateKind::Adt(_, variant_idx, _, _, _) = agg else {
-                            panic!()
+                        let discriminant = match agg {
+                            smir::AggregateKind::Adt(_, variant_idx, _, _, _) =>
+                                self.discriminant_for_variant_smir(
+                                    rv.ty(&self.locals_smir).unwrap(),
+                                    *variant_idx,
+                                    span,
+                                ),
+                            // A new coroutine is "unresumed", and its operands are the upvars.
+                            smir::AggregateKind::Coroutine(..) => Int::ZERO,
+                            _ => panic!(),
                         };
-                        let discriminant = self.discriminant_for_variant_smir(
-                            rv.ty(&self.locals_smir).unwrap(),
-                            *variant_idx,
-                            span,
-                        );
                         let ops: List<_> =
                             operands.iter().map(|x| self.translate_operand_smir(x, span)).collect();
 
@@ -130,20 +153,34 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                     x => rs::span_bug!(span, "Invalid aggregate type: {x:?}"),
                 }
             }
+            // This is introduced by the `Derefer` MIR pass for nested derefs like `**x`:
+            // it copies the inner pointer into a temporary, which is then dereferenced.
             smir::Rvalue::CopyForDeref(place) =>
                 ValueExpr::Load { source: GcCow::new(self.translate_place_smir(place, span)) },
             smir::Rvalue::Len(place) => {
-                // as slices are unsupported as of now, we only need to care for arrays.
+                // For slices, this reads the length from the metadata of the pointer to the place.
                 let ty = place.ty(&self.locals_smir).unwrap();
-                let Type::Array { elem: _, count } = self.translate_ty_smir(ty, span) else {
-                    panic!()
+                let (Type::Array { .. } | Type::Slice { .. }) = self.translate_ty_smir(ty, span)
+                else {
+                    rs::span_bug!(span, "Len of non-array type {ty:?} is not supported!")
                 };
-                ValueExpr::Constant(Constant::Int(count), <usize>::get_type())
+                ValueExpr::Len { place: GcCow::new(self.translate_place_smir(place, span)) }
+            }
+            smir::Rvalue::Discriminant(place) => {
+                let place_ty = place.ty(&self.locals_smir).unwrap();
+                match self.translate_ty_smir(place_ty, span) {
+                    Type::Enum { .. } =>
+                        ValueExpr::GetDiscriminant {
+                            place: GcCow::new(self.translate_place_smir(place, span)),
+                        },
+                    // Rust defines the discriminant of all other types to be 0.
+                    _ => {
+                        let discr_ty = rv.ty(&self.locals_smir).unwrap();
+                        let discr_ty = self.translate_ty_smir(discr_ty, span);
+                        ValueExpr::Constant(Constant::Int(Int::ZERO), discr_ty)
+                    }
+                }
             }
-            smir::Rvalue::Discriminant(place) =>
-                ValueExpr::GetDiscriminant {
-                    place: GcCow::new(self.translate_place_smir(place, span)),
-                },
             smir::Rvalue::Repeat(op, c) => {
                 let c = c.eval_target_usize().unwrap();
                 let c = Int::from(c);
@@ -185,8 +222,26 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                     "PointerWithExposedProvenance should have been handled on the statement level"
                 );
             }
+            // Casting a wide pointer to a thin pointer drops the metadata.
+            smir::Rvalue::Cast(smir::CastKind::PtrToPtr, operand, ty)
+                if self.is_wide_ptr_smir(operand.ty(&self.locals_smir).unwrap(), span)
+                    && !self.is_wide_ptr_smir(*ty, span) =>
+            {
+                let operand = self.translate_operand_smir(operand, span);
+                let ty = self.translate_ty_smir(*ty, span);
+                build::transmute(build::get_thin_pointer(operand), ty)
+            }
             smir::Rvalue::Cast(
-                smir::CastKind::Transmute | smir::CastKind::PtrToPtr | smir::CastKind::FnPtrToPtr,
+                smir::CastKind::Transmute
+                | smir::CastKind::PtrToPtr
+                | smir::CastKind::FnPtrToPtr
+                // All of these only change the pointee type of a thin pointer,
+                // which does not affect the representation.
+                | smir::CastKind::PointerCoercion(
+                    smir::PointerCoercion::MutToConstPointer
+                    | smir::PointerCoercion::ArrayToPointer
+                    | smir::PointerCoercion::UnsafeFnPointer,
+                ),
                 operand,
                 ty,
             ) => {
@@ -197,7 +252,7 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
             smir::Rvalue::Cast(
                 smir::CastKind::PointerCoercion(smir::PointerCoercion::ReifyFnPointer),
                 func,
-                _,
+                ty,
             ) => {
                 let smir::Operand::Constant(f1) = func else { panic!() };
                 let smir::TyKind::RigidTy(smir::RigidTy::FnDef(f, substs_ref)) = f1.ty().kind()
@@ -206,7 +261,39 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                 };
                 let instance = smir::Instance::resolve(f, &substs_ref).unwrap();
 
-                build::fn_ptr(self.cx.get_fn_name_smir(instance).0.get_internal())
+                // The calling convention of the pointer type has to match the destination's.
+                let ty = self.translate_ty_smir(*ty, span);
+                ValueExpr::Constant(Constant::FnPointer(self.cx.get_fn_name_smir(instance)), ty)
+            }
+            smir::Rvalue::Cast(
+                smir::CastKind::PointerCoercion(smir::PointerCoercion::Unsize),
+                operand,
+                ty,
+            ) => {
+                let operand_ty = smir::internal(self.tcx, operand.ty(&self.locals_smir).unwrap());
+                let operand = self.translate_operand_smir(operand, span);
+                let ty = smir::internal(self.tcx, *ty);
+                self.translate_unsize(operand, operand_ty, ty, span)
+            }
+            // The closure body takes the closure itself as an additional first argument, which a
+            // call through the resulting function pointer does not pass.
+            smir::Rvalue::Cast(
+                smir::CastKind::PointerCoercion(smir::PointerCoercion::ClosureFnPointer(_)),
+                ..,
+            ) => rs::span_bug!(span, "Closure to function pointer casts are not supported!"),
+            smir::Rvalue::Cast(
+                smir::CastKind::FloatToInt
+                | smir::CastKind::FloatToFloat
+                | smir::CastKind::IntToFloat,
+                ..,
+            ) => rs::span_bug!(span, "Float casts are not supported, MiniRust has no floats!"),
+            // Part of the `Box::new` lowering: turns the freshly allocated memory into a box,
+            // while the pointee is still uninitialized. We represent boxes as pointers, hence
+            // this is just a transmute.
+            smir::Rvalue::ShallowInitBox(operand, _pointee_ty) => {
+                let operand = self.translate_operand_smir(operand, span);
+                let ty = self.translate_ty_smir(rv.ty(&self.locals_smir).unwrap(), span);
+                build::transmute(operand, ty)
             }
             smir::Rvalue::NullaryOp(smir::NullOp::UbChecks, _ty) => {
                 // Like Miri, since we are able to detect language UB ourselves we can disable these checks.
@@ -226,6 +313,8 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
             smir::Operand::Constant(c) => self.translate_const_smir(&c.literal, span),
             smir::Operand::Copy(place) =>
                 ValueExpr::Load { source: GcCow::new(self.translate_place_smir(place, span)) },
+            // Whether a move de-initializes its source is not decided for Rust yet, so like Miri
+            // we treat it as a copy. `build::assign_move` models the stricter semantics.
             smir::Operand::Move(place) =>
                 ValueExpr::Load { source: GcCow::new(self.translate_place_smir(place, span)) },
         }
@@ -244,6 +333,27 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
             place.projection.iter().fold((expr, place_ty), |(expr, place_ty), proj| {
                 let this_ty = proj.ty(place_ty).unwrap();
                 let this_expr = match proj {
+                    // `ElaborateBoxDerefs` accesses the pointer inside a box via its fields.
+                    // We represent boxes as pointers, but the first field has the same
+                    // representation and the second field is a zero-sized allocator,
+                    // so we just reinterpret the box as the field.
+                    smir::ProjectionElem::Field(_f, _ty) if place_ty.kind().is_box() => {
+                        let ty = self.translate_ty_smir(this_ty, span);
+                        build::deref(build::raw_addr_of(expr), ty)
+                    }
+                    // The upvars of a coroutine are fields of every variant, see
+                    // `translate_coroutine`. The "unresumed" variant has no other fields.
+                    // (A downcast keeps the coroutine type, but then this is a variant field.)
+                    smir::ProjectionElem::Field(f, _ty)
+                        if matches!(
+                            place_ty.kind().rigid(),
+                            Some(smir::RigidTy::Coroutine(..))
+                        ) && !matches!(expr, PlaceExpr::Downcast { .. }) =>
+                    {
+                        let root =
+                            PlaceExpr::Downcast { root: GcCow::new(expr), discriminant: Int::ZERO };
+                        PlaceExpr::Field { root: GcCow::new(root), field: (*f).into() }
+                    }
                     smir::ProjectionElem::Field(f, _ty) => {
                         let indirected = GcCow::new(expr);
                         PlaceExpr::Field { root: indirected, field: (*f).into() }
@@ -265,6 +375,37 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
                         let root = GcCow::new(expr);
                         PlaceExpr::Index { root, index: i }
                     }
+                    // Slice patterns on arrays. The length is always known statically,
+                    // so indices counting from the end can be resolved.
+                    smir::ProjectionElem::ConstantIndex { offset, min_length: _, from_end } => {
+                        let count = self.array_count(place_ty, span);
+                        let offset = Int::from(*offset);
+                        let i = if *from_end { count - offset } else { offset };
+                        let i = build::const_int_typed::<usize>(i);
+                        PlaceExpr::Index { root: GcCow::new(expr), index: GcCow::new(i) }
+                    }
+                    smir::ProjectionElem::Subslice { from, to, from_end } => {
+                        let count = self.array_count(place_ty, span);
+                        let end = if *from_end { count - Int::from(*to) } else { Int::from(*to) };
+                        assert!(Int::from(*from) <= end && end <= count);
+                        // The subarray starts at element `from`, so we offset a pointer to the
+                        // whole array (which the aliasing model lets us use for all its elements)
+                        // to that element, and reinterpret it as a pointer to the subarray.
+                        let Type::Array { elem, .. } = self.translate_ty_smir(place_ty, span)
+                        else {
+                            unreachable!()
+                        };
+                        let offset =
+                            elem.extract().size::<DefaultTarget>().bytes() * Int::from(*from);
+                        let offset = build::const_int_typed::<isize>(offset);
+                        let first = build::ptr_offset(
+                            build::raw_addr_of(expr),
+                            offset,
+                            build::InBounds::Yes,
+                        );
+                        let ty = self.translate_ty_smir(this_ty, span);
+                        build::deref(first, ty)
+                    }
                     smir::ProjectionElem::Downcast(variant_idx) => {
                         let root = GcCow::new(expr);
                         let discriminant =
@@ -277,4 +418,53 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
             });
         expr
     }
+
+    /// Whether `ty` is a pointer type with metadata.
+    fn is_wide_ptr_smir(&self, ty: smir::Ty, span: rs::Span) -> bool {
+        matches!(self.translate_ty_smir(ty, span), Type::Ptr(ptr_ty) if ptr_ty.is_wide())
+    }
+
+    /// Turns the thin pointer `operand` of type `operand_ty` into a wide pointer of type `ty`,
+    /// by adding the metadata for the unsized pointee.
+    fn translate_unsize(
+        &mut self,
+        operand: ValueExpr,
+        operand_ty: rs::Ty<'tcx>,
+        ty: rs::Ty<'tcx>,
+        span: rs::Span,
+    ) -> ValueExpr {
+        let (Some(source), Some(target)) = (operand_ty.builtin_deref(true), ty.builtin_deref(true))
+        else {
+            rs::span_bug!(span, "Unsize cast from {operand_ty} to {ty} is not supported!")
+        };
+        let (source, target) = (source.ty, target.ty);
+        if source == target {
+            // This only changes lifetimes, e.g. of a trait object, so the metadata stays the same.
+            let ty = self.translate_ty(ty, span);
+            return build::transmute(operand, ty);
+        }
+        let meta = match (source.kind(), target.kind()) {
+            (rs::TyKind::Array(_, count), rs::TyKind::Slice(_)) => {
+                let count = count.eval_target_usize(self.tcx, rs::ParamEnv::reveal_all());
+                build::const_int_typed::<usize>(Int::from(count))
+            }
+            (_, rs::TyKind::Dynamic(preds, ..))
+                if source.is_sized(self.tcx, rs::ParamEnv::reveal_all()) =>
+            {
+                let vtable = self.cx.get_vtable_name(source, preds.principal());
+                ValueExpr::Constant(Constant::VTablePointer(vtable), build::raw_ptr_ty())
+            }
+            _ => rs::span_bug!(span, "Unsize cast from {operand_ty} to {ty} is not supported!"),
+        };
+        let ty = self.translate_ty(ty, span);
+        build::construct_wide_pointer(operand, meta, ty)
+    }
+
+    /// The number of elements of the array type `ty`.
+    fn array_count(&self, ty: smir::Ty, span: rs::Span) -> Int {
+        match self.translate_ty_smir(ty, span) {
+            Type::Array { count, .. } => count,
+            _ => rs::span_bug!(span, "slice patterns are only supported on arrays"),
+        }
+    }
 }
@@ -1,14 +1,38 @@
 use crate::*;
 
+use std::ops::Bound;
+
 impl<'tcx> Ctxt<'tcx> {
     pub fn layout_of(&self, ty: rs::Ty<'tcx>) -> Layout {
+        let freeze = ty.is_freeze(self.tcx, rs::ParamEnv::reveal_all());
+        // For unsized types, the layout only describes what is known statically;
+        // the rest comes from the pointer metadata.
+        match ty.kind() {
+            rs::TyKind::Slice(elem) => {
+                let elem = self.layout_of(*elem);
+                return Layout { meta_kind: PointerMetaKind::ElementCount, freeze, ..elem };
+            }
+            rs::TyKind::Str => {
+                let elem = self.layout_of(self.tcx.types.u8);
+                return Layout { meta_kind: PointerMetaKind::ElementCount, freeze, ..elem };
+            }
+            rs::TyKind::Dynamic(..) =>
+                return Layout {
+                    size: Size::ZERO,
+                    align: Align::ONE,
+                    inhabited: true,
+                    freeze,
+                    meta_kind: PointerMetaKind::VTablePointer,
+                },
+            _ => {}
+        }
         let layout = self.rs_layout_of(ty);
         assert!(layout.is_sized(), "encountered unsized type: {ty}");
         let size = translate_size(layout.size());
         let align = translate_align(layout.align().abi);
         let inhabited = !layout.abi().is_uninhabited();
 
-        Layout { size, align, inhabited }
+        Layout { size, align, inhabited, freeze, meta_kind: PointerMetaKind::None }
     }
 
     pub fn layout_of_smir(&self, ty: smir::Ty) -> Layout {
@@ -30,7 +54,13 @@ impl<'tcx> Ctxt<'tcx> {
                 let sz = rs::abi::Integer::from_uint_ty(&self.tcx, *t).size();
                 Type::Int(IntType { size: translate_size(sz), signed: Signedness::Unsigned })
             }
-            rs::TyKind::Tuple(ts) => {
+            // A closure is represented like a tuple of the variables it captures.
+            rs::TyKind::Tuple(_) | rs::TyKind::Closure(..) => {
+                let ts = match ty.kind() {
+                    rs::TyKind::Closure(_, args) => args.as_closure().upvar_tys(),
+                    rs::TyKind::Tuple(ts) => ts,
+                    _ => unreachable!(),
+                };
                 let layout = self.rs_layout_of(ty);
                 let size = translate_size(layout.size());
                 let align = translate_align(layout.align().abi);
@@ -47,7 +77,7 @@ impl<'tcx> Ctxt<'tcx> {
                     })
                     .collect();
 
-                Type::Tuple { fields, size, align }
+                Type::Tuple { fields, size, align, valid_range: None }
             }
             rs::TyKind::Adt(adt_def, _) if adt_def.is_box() => {
                 let ty = ty.boxed_ty();
@@ -56,7 +86,8 @@ impl<'tcx> Ctxt<'tcx> {
             }
             rs::TyKind::Adt(adt_def, sref) if adt_def.is_struct() => {
                 let (fields, size, align) = self.translate_non_enum_adt(ty, *adt_def, sref, span);
-                Type::Tuple { fields, size, align }
+                let valid_range = self.translate_scalar_valid_range(ty, *adt_def, fields);
+                Type::Tuple { fields, size, align, valid_range }
             }
             rs::TyKind::Adt(adt_def, sref) if adt_def.is_union() => {
                 let (fields, size, align) = self.translate_non_enum_adt(ty, *adt_def, sref, span);
@@ -71,9 +102,18 @@ impl<'tcx> Ctxt<'tcx> {
                 Type::Ptr(PtrType::Ref { pointee, mutbl })
             }
             rs::TyKind::RawPtr(ty, _mutbl) => {
-                let _pointee = self.layout_of(*ty); // just to make sure that we can translate this type
-                Type::Ptr(PtrType::Raw)
+                let pointee = self.layout_of(*ty);
+                Type::Ptr(PtrType::Raw { meta_kind: pointee.meta_kind })
+            }
+            rs::TyKind::Slice(elem) => {
+                let elem = GcCow::new(self.translate_ty(*elem, span));
+                Type::Slice { elem }
             }
+            rs::TyKind::Str => {
+                let elem = GcCow::new(self.translate_ty(self.tcx.types.u8, span));
+                Type::Slice { elem }
+            }
+            rs::TyKind::Dynamic(..) => Type::TraitObject,
             rs::TyKind::Array(ty, c) => {
                 let count = Int::from(c.eval_target_usize(self.tcx, rs::ParamEnv::reveal_all()));
                 let elem = GcCow::new(self.translate_ty(*ty, span));
@@ -89,6 +129,8 @@ impl<'tcx> Ctxt<'tcx> {
             }
             rs::TyKind::Never =>
                 build::enum_ty::<u8>(&[], Discriminator::Invalid, build::size(0), build::align(1)),
+            rs::TyKind::Coroutine(def_id, args) =>
+                self.translate_coroutine(ty, *def_id, args, span),
             x => rs::span_bug!(span, "TyKind not supported: {x:?}"),
         }
     }
@@ -118,6 +160,36 @@ impl<'tcx> Ctxt<'tcx> {
             .collect()
     }
 
+    /// Structs marked with `#[rustc_layout_scalar_valid_range_start/end]` (like `NonZero*` and
+    /// `NonNull`) restrict the values of their only field. Returns that range, if any.
+    fn translate_scalar_valid_range(
+        &self,
+        ty: rs::Ty<'tcx>,
+        adt_def: rs::AdtDef<'tcx>,
+        fields: Fields,
+    ) -> Option<(Int, Int)> {
+        let (start, end) = self.tcx.layout_scalar_valid_range(adt_def.did());
+        if start == Bound::Unbounded && end == Bound::Unbounded {
+            return None;
+        }
+        // The attribute is only allowed on structs with a single scalar field, and the layout
+        // of the struct is then a scalar with the restricted range (and defaults filled in).
+        // For wide pointers like `NonNull<[T]>`, the range restricts the address, which is the
+        // first scalar of the pair.
+        let (rs::abi::Abi::Scalar(scalar) | rs::abi::Abi::ScalarPair(scalar, _)) =
+            self.rs_layout_of(ty).abi()
+        else {
+            panic!("scalar valid range on a non-scalar type: {ty}")
+        };
+        let range = scalar.valid_range(&self.tcx);
+        let field_ty = match fields.index_at(0).1 {
+            Type::Int(int_ty) => int_ty,
+            Type::Ptr(_) => IntType { signed: Signedness::Unsigned, size: DefaultTarget::PTR_SIZE },
+            _ => panic!("scalar valid range on a field that is not an integer or pointer: {ty}"),
+        };
+        Some((int_from_bits(range.start, field_ty), int_from_bits(range.end, field_ty)))
+    }
+
     fn translate_non_enum_adt(
         &self,
         ty: rs::Ty<'tcx>,
@@ -146,13 +218,6 @@ pub fn translate_mutbl(mutbl: rs::Mutability) -> Mutability {
     }
 }
 
-pub fn translate_mutbl_smir(mutbl: smir::Mutability) -> Mutability {
-    match mutbl {
-        smir::Mutability::Mut => Mutability::Mutable,
-        smir::Mutability::Not => Mutability::Immutable,
-    }
-}
-
 pub fn translate_size(size: rs::Size) -> Size {
     Size::from_bytes_const(size.bytes())
 }
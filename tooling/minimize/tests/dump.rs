@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Runs `minimize` on a tiny program with `--minimize-dump-to` plus `extra_args`,
+/// and returns the dumped program.
+fn dump_with(name: &str, extra_args: &[&str]) -> String {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("dump");
+    std::fs::create_dir_all(&dir).unwrap();
+    let src = dir.join(format!("{name}.rs"));
+    std::fs::write(&src, "fn main() {}\n").unwrap();
+    let out = dir.join(format!("{name}.mini"));
+    let _ = std::fs::remove_file(&out);
+
+    let status = Command::new(env!("CARGO_BIN_EXE_minimize"))
+        .arg(&src)
+        .arg(format!("--minimize-dump-to={}", out.display()))
+        .args(extra_args)
+        .arg("--out-dir")
+        .arg(&dir)
+        .status()
+        .unwrap();
+    assert!(status.success());
+    std::fs::read_to_string(&out).unwrap()
+}
+
+#[test]
+fn dump_to_file() {
+    let dump = dump_with("dump_to_file", &[]);
+    assert!(dump.contains("start fn f"));
+}
+
+#[test]
+fn dump_fn_filter() {
+    // No function has this name, so only the (empty) type and global sections remain.
+    let dump = dump_with("dump_fn_filter", &["--minimize-dump-fn=f999999"]);
+    assert!(!dump.contains("fn f"));
+}
@@ -0,0 +1,10 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    // The `Assert` guarding against this overflow panics,
+    // so we never reach the UB of MiniRust's division.
+    print(i32::MIN / black_box(-1));
+}
+
+fn black_box<T>(t: T) -> T { t }
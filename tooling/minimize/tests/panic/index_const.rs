@@ -0,0 +1,10 @@
+#![allow(unconditional_panic)]
+
+fn f() -> u32 {
+    let v = [0u32; 1];
+    v[2]
+}
+
+fn main() {
+    f();
+}
@@ -0,0 +1,11 @@
+//@compile-flags: --minimize-function=get
+#![allow(dead_code)]
+
+use std::num::NonZeroU32;
+
+// Zero is not a valid `NonZeroU32`, so this function cannot be called with zeroed arguments.
+fn get(x: NonZeroU32) -> u32 {
+    x.get()
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+// MiniRust has no slices, so the slice patterns are matched on arrays.
+fn first_plus_last(s: [u8; 4]) -> u8 {
+    match s {
+        [a, .., b] => a + b,
+    }
+}
+
+fn sum_rest(s: [u8; 4]) -> u8 {
+    let [_, rest @ ..] = s;
+    let [x, y, z] = rest;
+    x + y + z
+}
+
+fn main() {
+    print(first_plus_last([1, 2, 3, 4]));
+    print(sum_rest([1, 2, 3, 4]));
+}
@@ -0,0 +1,10 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    let x = [7u8; 4];
+    print(x[0]);
+    print(x[3]);
+    let _y = [(); 5];
+    let _z = [1u8; 0];
+}
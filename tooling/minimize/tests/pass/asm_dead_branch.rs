@@ -0,0 +1,16 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+// Inline assembly cannot be modeled, but as long as it is never reached the rest of the
+// program runs fine.
+fn f(x: bool) -> u32 {
+    if x {
+        unsafe { std::arch::asm!("nop") };
+        return 1;
+    }
+    2
+}
+
+fn main() {
+    print(f(black_box(false)));
+}
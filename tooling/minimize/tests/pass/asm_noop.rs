@@ -0,0 +1,9 @@
+//@compile-flags: --minimize-asm=noop
+extern crate intrinsics;
+use intrinsics::*;
+
+// With `--minimize-asm=noop`, inline assembly is translated to a jump to its fallthrough block.
+fn main() {
+    unsafe { std::arch::asm!("nop") };
+    print(42);
+}
@@ -0,0 +1,14 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    let mut x: u32 = 0;
+    let ptr = (&mut x) as *mut u32;
+
+    // With a single thread, every load sees the latest store, whatever the ordering.
+    unsafe { atomic_store_relaxed(ptr, 1) };
+    print(unsafe { atomic_load_relaxed(ptr) });
+    unsafe { atomic_store_release(ptr, 2) };
+    print(unsafe { atomic_load_acquire(ptr) });
+    print(unsafe { atomic_load(ptr) });
+}
@@ -0,0 +1,10 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    let x = black_box(0x8000_0000_0000_0000_0000_0000_0000_0003u128);
+    print(x.rotate_left(130) == x.rotate_left(2));
+    print(x.rotate_right(1) == 0xc000_0000_0000_0000_0000_0000_0000_0001);
+    print(x.reverse_bits() == 0xc000_0000_0000_0000_0000_0000_0000_0001);
+    print(black_box(-2i128).swap_bytes() == -0x0100_0000_0000_0000_0000_0000_0000_0001);
+}
@@ -0,0 +1,25 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn bump(x: &mut u32) {
+    *x += 1;
+}
+
+fn read(x: &u32) -> u32 {
+    *x
+}
+
+fn main() {
+    let mut x = 1;
+    // A mutable borrow, written through.
+    let r = &mut x;
+    *r = 5;
+    bump(r);
+    bump(&mut x);
+    // A shared borrow, only read.
+    print(read(&x));
+    // A shared borrow while computing the index of a write.
+    let mut v = [0u32; 2];
+    v[read(&x) as usize - 7] = 3;
+    print(v[0]);
+}
@@ -0,0 +1,14 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    let mut b = Box::new(5);
+    print(*b);
+    *b = 7;
+    print(*b);
+
+    // Dropping boxes is not supported yet, so we free the memory by hand.
+    let p = &mut *b as *mut i32;
+    std::mem::forget(b);
+    unsafe { deallocate(p as *mut u8, 4, 4) };
+}
@@ -0,0 +1,15 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn black_box<T>(t: T) -> T { t }
+
+fn main() {
+    let x = black_box(3);
+    if likely(x > 2) {
+        print(1);
+    }
+    if unlikely(x > 5) {
+        print(2);
+    }
+    print(likely(false));
+}
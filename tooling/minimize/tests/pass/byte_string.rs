@@ -0,0 +1,25 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn bytes() -> &'static [u8; 3] {
+    b"abc"
+}
+
+fn slice() -> &'static [u8] {
+    // This constant is a wide pointer to the bytes, with length 3.
+    b"abc"
+}
+
+fn main() {
+    let s = bytes();
+    print(s[0]);
+    print(s[2]);
+    let [_, b, _] = *b"abc";
+    print(b);
+
+    let s = slice();
+    print(s.len());
+    print(s[0]);
+    print(s[2]);
+    print("hello".len());
+}
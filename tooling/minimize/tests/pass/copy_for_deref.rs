@@ -0,0 +1,21 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+// Dereferencing a reference that itself lives behind a reference
+// makes MIR copy the inner reference out first, via `CopyForDeref`.
+fn get(x: &&[u32; 3], i: usize) -> u32 {
+    x[i]
+}
+
+fn set(x: &mut &mut [u32; 3], i: usize, val: u32) {
+    x[i] = val;
+}
+
+fn main() {
+    let mut arr = [1, 2, 3];
+    set(&mut &mut arr, 1, 20);
+    let r = &arr;
+    print(get(&r, 0));
+    print(get(&r, 1));
+    print(get(&r, 2));
+}
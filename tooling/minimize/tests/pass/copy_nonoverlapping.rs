@@ -0,0 +1,18 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::mem::size_of_val;
+use std::ptr::copy_nonoverlapping;
+
+fn main() {
+    let src = [1u16, 2, 3, 4];
+    let mut dst = [0u16; 4];
+    // Copy the first two elements; the pointers cover the whole arrays.
+    let src_ptr = &src as *const [u16; 4] as *const u16;
+    let dst_ptr = &mut dst as *mut [u16; 4] as *mut u16;
+    unsafe { copy_nonoverlapping(src_ptr, dst_ptr, 2) };
+    print(dst[0]);
+    print(dst[1]);
+    print(dst[2]);
+    print(size_of_val(&dst));
+}
@@ -0,0 +1,27 @@
+#![feature(coroutines, coroutine_trait, stmt_expr_attributes)]
+extern crate intrinsics;
+use intrinsics::*;
+use std::ops::{Coroutine, CoroutineState};
+use std::pin::Pin;
+
+fn resume<C: Coroutine<Yield = u32, Return = u32> + Unpin>(c: &mut C) {
+    match Pin::new(c).resume(()) {
+        CoroutineState::Yielded(v) => print(v),
+        CoroutineState::Complete(v) => print(v + 100),
+    }
+}
+
+fn main() {
+    let x = 5u32;
+    // `x` is an upvar, `y` is saved in the state of the second suspension point.
+    let mut c = #[coroutine]
+    move || {
+        yield x;
+        let y = x + 1;
+        yield y;
+        y + 1
+    };
+    resume(&mut c);
+    resume(&mut c);
+    resume(&mut c);
+}
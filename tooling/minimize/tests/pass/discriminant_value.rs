@@ -0,0 +1,23 @@
+#![feature(core_intrinsics)]
+#![allow(internal_features)]
+
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::intrinsics::discriminant_value;
+use std::num::NonZeroU8;
+
+enum E {
+    A = 3,
+    B = 7,
+}
+
+fn main() {
+    print(discriminant_value(&E::A) as i64);
+    print(discriminant_value(&E::B) as i64);
+    // Niche-encoded: the discriminant is the logical one, not the stored byte.
+    print(discriminant_value(&None::<NonZeroU8>) as i64);
+    print(discriminant_value(&NonZeroU8::new(42)) as i64);
+    // Non-enum types have discriminant 0.
+    print(discriminant_value(&(1u8, 2u8)) as i64);
+}
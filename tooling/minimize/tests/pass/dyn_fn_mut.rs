@@ -0,0 +1,38 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn apply(f: &mut dyn FnMut() -> i32) -> i32 {
+    // This calls the closure through the vtable, passing the environment pointer as `self`.
+    f()
+}
+
+fn call_twice<F: FnMut(i32) -> i32>(mut f: F) -> i32 {
+    f(1) + f(2)
+}
+
+fn main() {
+    let mut count = 0;
+    let mut add = || {
+        count += 1;
+        count * 10
+    };
+    print(apply(&mut add));
+    print(apply(&mut add));
+    print(count);
+
+    let mut total = 0;
+    let mut cb: Box<dyn FnMut(i32) -> i32> = Box::new(|x| {
+        total += x;
+        total
+    });
+    print(cb(5));
+    // This goes through the `FnMut` impl of `&mut F`, which forwards its tupled arguments.
+    print(call_twice(&mut cb));
+
+    // Dropping boxes is not supported yet, so we free the memory by hand.
+    // The closure only captures `&mut total`.
+    let p = &mut *cb as *mut dyn FnMut(i32) -> i32 as *mut u8;
+    std::mem::forget(cb);
+    unsafe { deallocate(p, 8, 8) };
+    print(total);
+}
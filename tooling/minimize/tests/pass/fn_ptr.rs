@@ -0,0 +1,24 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn double(x: i32) -> i32 {
+    x * 2
+}
+
+fn inc(x: i32) -> i32 {
+    x + 1
+}
+
+const INC: fn(i32) -> i32 = inc;
+
+fn apply(f: fn(i32) -> i32, x: i32) -> i32 {
+    f(x)
+}
+
+fn main() {
+    let p: fn(i32) -> i32 = double;
+    print(p(3));
+    // A function pointer that is a constant itself.
+    print(INC(3));
+    print(apply(INC, apply(p, 5)));
+}
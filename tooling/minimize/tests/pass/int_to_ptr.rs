@@ -0,0 +1,10 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    let x = 42i32;
+    // Casting the pointer to an integer exposes its provenance, which the cast back picks up.
+    let addr = &x as *const i32 as usize;
+    let ptr = addr as *const i32;
+    print(unsafe { *ptr });
+}
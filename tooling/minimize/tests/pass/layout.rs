@@ -0,0 +1,22 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::mem::{align_of, size_of};
+use std::num::NonZeroU8;
+
+#[allow(dead_code)]
+enum E {
+    A(u32),
+    B(u8),
+}
+
+fn main() {
+    // Niche-optimized enums.
+    print(size_of::<Option<&u8>>());
+    print(size_of::<Option<NonZeroU8>>());
+    print(size_of::<Option<bool>>());
+    // Enums with a tag.
+    print(size_of::<Option<u32>>());
+    print(align_of::<Option<u32>>());
+    print(size_of::<E>());
+}
@@ -0,0 +1,13 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn get(x: [i32; 3], i: usize) -> i32 {
+    // Indexing with a runtime index checks the index against the length of the array.
+    x[i]
+}
+
+fn main() {
+    let x = [10, 20, 30];
+    print(get(x, 0));
+    print(get(x, 2));
+}
@@ -0,0 +1,15 @@
+#![feature(core_intrinsics)]
+#![allow(internal_features)]
+
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::intrinsics::{likely, unlikely};
+
+// These are only hints and return their argument.
+fn main() {
+    print(likely(true));
+    print(likely(false));
+    print(unlikely(true));
+    print(unlikely(false));
+}
@@ -0,0 +1,23 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+enum Shape {
+    Point,
+    Circle(u32),
+    Rect(u32, u32),
+}
+
+fn area(s: Shape) -> u32 {
+    // `match` is lowered to a `SwitchInt` on the `Discriminant` of `s`.
+    match s {
+        Shape::Point => 0,
+        Shape::Circle(r) => 3 * r * r,
+        Shape::Rect(w, h) => w * h,
+    }
+}
+
+fn main() {
+    print(area(Shape::Point));
+    print(area(Shape::Circle(3)));
+    print(area(Shape::Rect(3, 4)));
+}
@@ -0,0 +1,20 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+// Matches with guards and `let _ = ...` generate `FakeRead` and `PlaceMention` statements
+// in MIR building. They have no effect at runtime.
+fn classify(x: Option<u8>) -> u8 {
+    match x {
+        Some(n) if n > 10 => 2,
+        Some(_) => 1,
+        None => 0,
+    }
+}
+
+fn main() {
+    let v = black_box(5u8);
+    let _ = v;
+    print(classify(Some(v)));
+    print(classify(Some(black_box(20))));
+    print(classify(None));
+}
@@ -0,0 +1,30 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn small(x: u32) -> u32 {
+    match x {
+        0..=3 => 1,
+        _ => 2,
+    }
+}
+
+// A range this large must not turn into one switch case per value.
+fn large(x: i64) -> u32 {
+    match x {
+        i64::MIN..=-1 => 0,
+        0..=1_000_000_000 => 1,
+        _ => 2,
+    }
+}
+
+fn main() {
+    print(small(0));
+    print(small(2));
+    print(small(3));
+    print(small(4));
+    print(small(100));
+    print(large(-5));
+    print(large(0));
+    print(large(1_000_000_000));
+    print(large(1_000_000_001));
+}
@@ -0,0 +1,13 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::mem::MaybeUninit;
+
+fn main() {
+    // Copying an uninitialized `MaybeUninit` is fine.
+    let x = MaybeUninit::<u32>::uninit();
+    let _y = black_box(x);
+
+    let z = MaybeUninit::new(42u32);
+    print(unsafe { z.assume_init() });
+}
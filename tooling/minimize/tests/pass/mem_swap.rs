@@ -0,0 +1,10 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    let mut a = 1u32;
+    let mut b = 2u32;
+    std::mem::swap(&mut a, &mut b);
+    print(a);
+    print(b);
+}
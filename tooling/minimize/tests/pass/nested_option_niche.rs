@@ -0,0 +1,67 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+include!("../helper/transmute.rs");
+
+// Every level of `Option` uses the next free niche value of the innermost `bool`.
+type Nested = Option<Option<Option<bool>>>;
+
+fn encode(x: Nested) -> u8 {
+    match x {
+        None => 0,
+        Some(None) => 1,
+        Some(Some(None)) => 2,
+        Some(Some(Some(false))) => 3,
+        Some(Some(Some(true))) => 4,
+    }
+}
+
+fn decode(i: u8) -> Nested {
+    match i {
+        0 => None,
+        1 => Some(None),
+        2 => Some(Some(None)),
+        3 => Some(Some(Some(false))),
+        _ => Some(Some(Some(true))),
+    }
+}
+
+// Several dataless variants share the niche of the one variant with data.
+enum Many {
+    Data(bool),
+    A,
+    B,
+    C,
+}
+
+fn many_to_int(m: Many) -> u8 {
+    match m {
+        Many::Data(false) => 0,
+        Many::Data(true) => 1,
+        Many::A => 2,
+        Many::B => 3,
+        Many::C => 4,
+    }
+}
+
+fn main() {
+    // Round-trip every value through setting and reading the discriminant.
+    let mut i = 0;
+    while i < 5 {
+        print(encode(decode(i)));
+        i += 1;
+    }
+    // The raw representations: `true` and `false` are 1 and 0, the `None`s count up from 2.
+    unsafe {
+        print(encode(transmute::<u8, Nested>(0)));
+        print(encode(transmute::<u8, Nested>(1)));
+        print(encode(transmute::<u8, Nested>(2)));
+        print(encode(transmute::<u8, Nested>(3)));
+        print(encode(transmute::<u8, Nested>(4)));
+    }
+    print(many_to_int(Many::Data(false)));
+    print(many_to_int(Many::Data(true)));
+    print(many_to_int(Many::A));
+    print(many_to_int(Many::B));
+    print(many_to_int(Many::C));
+}
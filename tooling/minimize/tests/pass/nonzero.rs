@@ -0,0 +1,14 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+include!("../helper/transmute.rs");
+
+use std::num::NonZeroU32;
+
+// Any value other than 0 is valid for a `NonZeroU32`.
+fn main() { unsafe {
+    let x = transmute::<u32, NonZeroU32>(7);
+    print(x.get());
+    let y = transmute::<u32, NonZeroU32>(u32::MAX);
+    print(y.get());
+} }
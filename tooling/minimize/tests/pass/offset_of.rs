@@ -0,0 +1,24 @@
+#![feature(offset_of_nested)]
+
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::mem::offset_of;
+
+#[repr(C)]
+struct S {
+    a: u8,
+    b: u32,
+}
+
+#[repr(C)]
+struct Outer {
+    x: u16,
+    s: S,
+}
+
+fn main() {
+    print(offset_of!(S, a));
+    print(offset_of!(S, b));
+    print(offset_of!(Outer, s.b));
+}
@@ -0,0 +1,14 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn get(o: Option<u32>) -> u32 {
+    match o {
+        Some(x) => x,
+        None => 42,
+    }
+}
+
+fn main() {
+    print(get(black_box(Some(3u32))));
+    print(get(black_box(None)));
+}
@@ -0,0 +1,17 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn f() -> &'static i32 {
+    &42
+}
+
+fn g() -> &'static [u8; 3] {
+    &[1, 2, 3]
+}
+
+fn main() {
+    print(*f());
+    print(g()[2]);
+    // Promoteds live in globals, so both calls return the same address.
+    print(f() as *const i32 == f() as *const i32);
+}
@@ -0,0 +1,19 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    let x = [200u8, 1];
+
+    // `*const u8` to `*const i8`.
+    let p = &x[0] as *const u8 as *const i8;
+    print(unsafe { *p });
+
+    // `*mut` to `*const`.
+    let mut y = 5u8;
+    let q: *const u8 = &mut y as *mut u8;
+    print(unsafe { *q });
+
+    // Array to pointer to its first element.
+    let r = &x as *const u8;
+    print(unsafe { *r });
+}
@@ -0,0 +1,12 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    let arr = [0u8; 4];
+    let p = &arr[0] as *const u8;
+    let q = &arr[2] as *const u8;
+    print(p == q);
+    print(p != q);
+    print(p < q);
+    print(q >= p);
+}
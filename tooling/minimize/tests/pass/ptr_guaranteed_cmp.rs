@@ -0,0 +1,14 @@
+#![feature(core_intrinsics)]
+#![allow(internal_features)]
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::intrinsics::ptr_guaranteed_cmp;
+
+fn main() {
+    let x = 0u8;
+    let y = 0u8;
+    let px = &x as *const u8;
+    print(ptr_guaranteed_cmp(px, px));
+    print(ptr_guaranteed_cmp(px, &y as *const u8));
+}
@@ -0,0 +1,37 @@
+//@compile-flags: -Zmir-emit-retag
+extern crate intrinsics;
+use intrinsics::*;
+
+// Function-entry retags for the arguments.
+fn swap(x: &mut u32, y: &mut u32) {
+    let tmp = *x;
+    *x = *y;
+    *y = tmp;
+}
+
+struct Counter(u32);
+
+impl Counter {
+    fn get(&self) -> u32 {
+        self.0
+    }
+
+    fn set(&mut self, v: u32) {
+        self.0 = v;
+    }
+}
+
+fn main() {
+    let mut a: u32 = 1;
+    let mut b = 2;
+    // Default retags for the references, raw retag for the cast.
+    let r = &mut a;
+    let raw = r as *mut u32;
+    swap(unsafe { &mut *raw }, &mut b);
+    // A two-phase borrow: `c` is borrowed mutably before `c.get()` is evaluated.
+    let mut c = Counter(3);
+    c.set(c.get() + 1);
+    print(a);
+    print(b);
+    print(c.0);
+}
@@ -0,0 +1,16 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::mem::{align_of, size_of};
+
+#[repr(C)]
+struct S {
+    a: u32,
+    b: u8,
+}
+
+fn main() {
+    print(size_of::<S>());
+    print(align_of::<S>());
+    print(size_of::<()>());
+}
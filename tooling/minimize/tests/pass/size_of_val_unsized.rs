@@ -0,0 +1,35 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::mem::{align_of_val, size_of_val};
+
+trait Trait {}
+
+#[allow(dead_code)]
+struct S {
+    a: u64,
+    b: u16,
+}
+
+impl Trait for S {}
+
+fn slice_size(x: &[u32]) -> usize {
+    // The size is computed from the length in the pointer metadata.
+    size_of_val(x)
+}
+
+fn dyn_layout(x: &dyn Trait) -> (usize, usize) {
+    // The size and alignment are read from the vtable.
+    (size_of_val(x), align_of_val(x))
+}
+
+fn main() {
+    let a = [1u32, 2, 3, 4, 5];
+    print(slice_size(&a));
+    print(align_of_val::<[u32]>(&a));
+
+    let s = S { a: 0, b: 0 };
+    let (size, align) = dyn_layout(&s);
+    print(size);
+    print(align);
+}
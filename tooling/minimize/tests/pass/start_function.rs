@@ -0,0 +1,12 @@
+//@compile-flags: --minimize-function=add_one
+#![allow(dead_code)]
+
+// With `--minimize-function`, the given function is called with zeroed arguments and its
+// return value is printed; `main` is not run.
+fn add_one(x: u32) -> u32 {
+    x + 1
+}
+
+fn main() {
+    unreachable!()
+}
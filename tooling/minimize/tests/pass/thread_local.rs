@@ -0,0 +1,28 @@
+#![feature(thread_local)]
+extern crate intrinsics;
+use intrinsics::*;
+
+#[thread_local]
+static mut COUNTER: u32 = 1;
+
+extern "C" fn thread(_: *const ()) {
+    unsafe {
+        // This thread starts out with the initial value, not with main's instance.
+        print(COUNTER);
+        COUNTER = 10;
+        print(COUNTER);
+    }
+}
+
+fn main() {
+    unsafe {
+        COUNTER += 1;
+    }
+    let data_ptr = &() as *const ();
+    let fn_ptr = thread as extern "C" fn(*const ());
+    let thread_id = spawn(fn_ptr, data_ptr);
+    join(thread_id);
+    unsafe {
+        print(COUNTER);
+    }
+}
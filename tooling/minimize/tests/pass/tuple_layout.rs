@@ -0,0 +1,30 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::mem::{offset_of, size_of};
+
+// The layouts that minitest's `tuple_layouts` expects from `TypeConv` for tuples.
+fn main() {
+    type A = (u8, u32);
+    print(offset_of!(A, 0));
+    print(offset_of!(A, 1));
+
+    type B = (u8, [u8; 4], u16, u32);
+    print(offset_of!(B, 0));
+    print(offset_of!(B, 1));
+    print(offset_of!(B, 2));
+    print(offset_of!(B, 3));
+    print(size_of::<B>());
+
+    type C = (u16, u8, u64, u32);
+    print(offset_of!(C, 0));
+    print(offset_of!(C, 1));
+    print(offset_of!(C, 2));
+    print(offset_of!(C, 3));
+    print(size_of::<C>());
+
+    type D = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u64);
+    print(offset_of!(D, 10));
+    print(offset_of!(D, 11));
+    print(size_of::<D>());
+}
@@ -0,0 +1,32 @@
+//@compile-flags: -Zmir-emit-retag
+extern crate intrinsics;
+use intrinsics::*;
+
+// A fixed-capacity stand-in for `Vec`, MiniRust has no heap-allocating collections.
+struct Stack {
+    data: [usize; 4],
+    len: usize,
+}
+
+impl Stack {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn push(&mut self, v: usize) {
+        self.data[self.len] = v;
+        self.len += 1;
+    }
+}
+
+fn main() {
+    let mut v = Stack { data: [0; 4], len: 0 };
+    // `&mut v` is a two-phase borrow: it is created before `v.len()` reads `v`.
+    v.push(v.len());
+    v.push(v.len());
+    v.push(v.len());
+    print(v.data[0]);
+    print(v.data[1]);
+    print(v.data[2]);
+    print(v.len());
+}
@@ -0,0 +1,28 @@
+#![feature(variant_count)]
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::any::TypeId;
+
+fn id_of<T: 'static>() -> TypeId {
+    TypeId::of::<T>()
+}
+
+#[allow(dead_code)]
+enum E {
+    A,
+    B,
+    C,
+}
+
+fn main() {
+    print(TypeId::of::<u32>() == TypeId::of::<u32>());
+    print(TypeId::of::<u32>() == id_of::<u32>());
+    print(TypeId::of::<u32>() == TypeId::of::<i32>());
+    print(std::mem::needs_drop::<u32>());
+    print(std::mem::variant_count::<E>());
+    // The name is a `&str` constant.
+    let name = std::any::type_name::<u32>().as_bytes();
+    print(name.len());
+    print(name[0]);
+}
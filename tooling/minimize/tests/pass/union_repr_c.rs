@@ -0,0 +1,25 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+// A C union, as used for type punning across FFI: all fields start at offset 0,
+// and reading a field just reinterprets the current bytes at that field's type.
+#[repr(C)]
+#[derive(Clone, Copy)]
+union IntBytes {
+    int: i32,
+    bytes: [u8; 4],
+}
+
+fn main() {
+    let u = IntBytes { int: 0x01020304 };
+    // minimize only supports little-endian targets (see `Ctxt::new`),
+    // so the least significant byte comes first.
+    let bytes = unsafe { u.bytes };
+    print(bytes[0]);
+    print(bytes[1]);
+    print(bytes[2]);
+    print(bytes[3]);
+
+    let u = IntBytes { bytes: [0xff, 0xff, 0xff, 0xff] };
+    print(unsafe { u.int });
+}
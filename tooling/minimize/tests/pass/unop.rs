@@ -0,0 +1,10 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    print(-black_box(5i32));
+    print(!black_box(0u8));
+    // Overflow checks are disabled, so this wraps around.
+    print(-black_box(i32::MIN));
+    print(!black_box(true));
+}
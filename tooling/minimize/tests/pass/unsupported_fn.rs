@@ -0,0 +1,25 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+#[derive(Clone, Copy)]
+#[allow(dead_code)]
+union U {
+    a: u32,
+    b: u8,
+}
+
+const C: U = U { a: 7 };
+
+// Constant unions are not supported, so this function is replaced by an `Unreachable` stub.
+// Since it is never called, the rest of the program still runs fine.
+fn unsupported() -> u32 {
+    let u = C;
+    unsafe { u.a }
+}
+
+fn main() {
+    print(42);
+    if false {
+        print(unsupported());
+    }
+}
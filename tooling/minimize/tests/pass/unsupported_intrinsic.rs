@@ -0,0 +1,14 @@
+#![feature(core_intrinsics)]
+#![allow(internal_features)]
+
+extern crate intrinsics;
+use intrinsics::*;
+
+// `ctpop` is not supported, so the call is replaced by an `Unreachable` terminator.
+// The rest of the function is still translated and runs fine.
+fn main() {
+    print(42);
+    if false {
+        print(std::intrinsics::ctpop(5u32));
+    }
+}
@@ -0,0 +1,9 @@
+use std::ptr::copy_nonoverlapping;
+
+fn main() {
+    let mut a = [1u8, 2, 3, 4];
+    let src = &mut a[0] as *mut u8;
+    let dst = &mut a[1] as *mut u8;
+    // The source and destination ranges share the bytes at index 1 and 2.
+    unsafe { copy_nonoverlapping(src, dst, 3) };
+}
@@ -0,0 +1,15 @@
+#![feature(coroutines, coroutine_trait, stmt_expr_attributes)]
+use std::ops::Coroutine;
+use std::pin::Pin;
+
+fn main() {
+    let mut c = #[coroutine]
+    || {
+        yield 1;
+        2
+    };
+    let _ = Pin::new(&mut c).resume(());
+    let _ = Pin::new(&mut c).resume(());
+    // The coroutine has returned, resuming it again is UB.
+    let _ = Pin::new(&mut c).resume(());
+}
@@ -0,0 +1,10 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn unit() {}
+
+fn main() {
+    let p: fn() = unit;
+    let q: fn(i32) -> i32 = unsafe { std::mem::transmute(p) };
+    print(q(3));
+}
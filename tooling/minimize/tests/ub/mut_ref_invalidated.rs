@@ -0,0 +1,8 @@
+fn main() {
+    let mut x = 0u32;
+    let p = &mut x as *mut u32;
+    let r = unsafe { &mut *p };
+    // Reading through the raw pointer invalidates `r`, which is not a two-phase borrow.
+    let _v = unsafe { *p };
+    *r = 1;
+}
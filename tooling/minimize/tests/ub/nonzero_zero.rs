@@ -0,0 +1,7 @@
+include!("../helper/transmute.rs");
+
+use std::num::NonZeroU32;
+
+fn main() { unsafe {
+    let _x = transmute::<u32, NonZeroU32>(0);
+} }
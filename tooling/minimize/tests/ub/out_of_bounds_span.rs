@@ -0,0 +1,6 @@
+//@compile-flags: --minimize-ub-span
+fn main() {
+    let x = [1u8, 2];
+    let p = &x as *const [u8; 2] as *const [u8; 3];
+    let _y = unsafe { *p };
+}
@@ -0,0 +1,5 @@
+fn main() {
+    let b = 3u8;
+    // The reference itself is fine, but it points to an invalid `bool`.
+    let _r = unsafe { &*(&b as *const u8 as *const bool) };
+}
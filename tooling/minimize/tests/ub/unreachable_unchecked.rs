@@ -0,0 +1,3 @@
+fn main() {
+    unsafe { std::hint::unreachable_unchecked() }
+}
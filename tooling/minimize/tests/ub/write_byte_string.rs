@@ -0,0 +1,12 @@
+extern crate intrinsics;
+
+fn slice() -> &'static [u8] {
+    b"abc"
+}
+
+#[allow(invalid_reference_casting)]
+fn main() {
+    // The bytes of the constant may only be read.
+    let p = slice() as *const [u8] as *mut u8;
+    unsafe { *p = 1 };
+}
@@ -0,0 +1,10 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+#[allow(invalid_reference_casting)]
+fn main() {
+    let x = 0u32;
+    let p = &x as *const u32 as *mut u32;
+    unsafe { *p = 1 };
+    print(x);
+}
@@ -20,13 +20,13 @@ mod tests;
 
 #[track_caller]
 pub fn assert_stop(prog: Program) {
-    assert_eq!(run_program(prog), TerminationInfo::MachineStop);
+    assert_eq!(run_program(prog), TerminationInfo::MachineStop(Int::ZERO));
 }
 
 #[track_caller]
 pub fn assert_stop_always(prog: Program, attempts: usize) {
     for _ in 0..attempts {
-        assert_eq!(run_program(prog), TerminationInfo::MachineStop);
+        assert_eq!(run_program(prog), TerminationInfo::MachineStop(Int::ZERO));
     }
 }
 
@@ -43,7 +43,7 @@ pub fn assert_ub_eventually(prog: Program, attempts: usize, msg: &str) {
     let msg = minirust_rs::prelude::String::from_internal(msg.to_string());
     for _ in 0..attempts {
         match run_program(prog) {
-            TerminationInfo::MachineStop => continue,
+            TerminationInfo::MachineStop(_) => continue,
             TerminationInfo::Ub(res) if res == msg => {
                 // Got the expected result.
                 return;
@@ -74,16 +74,47 @@ pub fn assert_memory_leak(prog: Program) {
     assert_eq!(run_program(prog), TerminationInfo::MemoryLeak);
 }
 
+#[track_caller]
+pub fn assert_exit_code(prog: Program, code: i32) {
+    assert_eq!(run_program(prog), TerminationInfo::MachineStop(Int::from(code)));
+}
+
+#[track_caller]
+pub fn assert_abort(prog: Program) {
+    assert_eq!(run_program(prog), TerminationInfo::Abort);
+}
+
+/// Panics do not unwind, they abort the program (see `panic_fn`).
+#[track_caller]
+pub fn assert_panics(prog: Program) {
+    assert_eq!(run_program(prog), TerminationInfo::Abort);
+}
+
+#[track_caller]
+pub fn assert_step_limit(prog: Program, limit: usize) {
+    assert_eq!(run_program_with_step_limit(prog, limit), TerminationInfo::StepLimit);
+}
+
+#[track_caller]
+pub fn assert_stack_overflow(prog: Program, limit: usize) {
+    let config = RunConfig { stack_limit: Some(limit), ..RunConfig::default() };
+    assert_eq!(run_program_with_config(prog, config), TerminationInfo::StackOverflow);
+}
+
+#[track_caller]
+pub fn assert_type_layout(ty: Type, size: Size, align: Align) {
+    assert_eq!(ty.size::<miniutil::DefaultTarget>(), size, "type has unexpected size");
+    assert_eq!(ty.align::<miniutil::DefaultTarget>(), align, "type has unexpected alignment");
+}
+
 /// Run the program multiple times. Checks if we get a data race in some execution
 /// This automatically fails if the program does not terminate correctly if the data race did not occur.
 #[track_caller]
 pub fn has_data_race(prog: Program) -> bool {
-    let data_race_string = minirust_rs::prelude::String::from_internal("Data race".to_string());
-
     for _ in 0..32 {
         match run_program(prog) {
-            TerminationInfo::MachineStop => {}
-            TerminationInfo::Ub(ub) if ub == data_race_string => {
+            TerminationInfo::MachineStop(_) => {}
+            TerminationInfo::DataRace => {
                 return true;
             }
             termination_info => {
@@ -0,0 +1,125 @@
+use crate::*;
+
+/// Writing through a raw pointer cast from a shared reference is UB.
+#[test]
+fn write_through_shared_ref() {
+    let locals = [<u32>::get_type(), <*mut u32>::get_type()];
+    let shared = ref_(local(0), <u32>::get_type(), Mutability::Immutable);
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), const_int(0u32)),
+        assign(local(1), ptr_to_ptr(shared, <*mut u32>::get_type())),
+        assign(deref(load(local(1)), <u32>::get_type()), const_int(1u32)),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ub(p, "write through a pointer derived from a shared reference");
+}
+
+/// The same write through a raw pointer cast from a mutable reference is fine.
+#[test]
+fn write_through_mut_ref() {
+    let locals = [<u32>::get_type(), <*mut u32>::get_type()];
+    let unique = ref_(local(0), <u32>::get_type(), Mutability::Mutable);
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), const_int(0u32)),
+        assign(local(1), ptr_to_ptr(unique, <*mut u32>::get_type())),
+        assign(deref(load(local(1)), <u32>::get_type()), const_int(1u32)),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_stop(p);
+}
+
+/// Reading the local directly invalidates a mutable reference to it.
+#[test]
+fn mut_ref_invalidated_by_read() {
+    let locals = [<u32>::get_type(), <&mut u32>::get_type(), <u32>::get_type()];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        assign(local(0), const_int(0u32)),
+        assign(local(1), ref_(local(0), <u32>::get_type(), Mutability::Mutable)),
+        assign(local(2), load(local(0))),
+        assign(deref(load(local(1)), <u32>::get_type()), const_int(1u32)),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ub(p, "memory access through a pointer whose borrow has been invalidated");
+}
+
+/// A two-phase borrow stays usable while the local is read, until it is first written to.
+#[test]
+fn two_phase_ref_survives_read() {
+    let locals = [<u32>::get_type(), <&mut u32>::get_type(), <u32>::get_type()];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        assign(local(0), const_int(0u32)),
+        assign(local(1), two_phase_ref_mut(local(0), <u32>::get_type())),
+        assign(local(2), load(local(0))),
+        assign(deref(load(local(1)), <u32>::get_type()), const_int(1u32)),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_stop(p);
+}
+
+/// Once a two-phase borrow has been written to, it is unique like any other mutable reference.
+#[test]
+fn two_phase_ref_activated_by_write() {
+    let locals = [<u32>::get_type(), <&mut u32>::get_type(), <u32>::get_type()];
+    let place = deref(load(local(1)), <u32>::get_type());
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        assign(local(0), const_int(0u32)),
+        assign(local(1), two_phase_ref_mut(local(0), <u32>::get_type())),
+        assign(place, const_int(1u32)),
+        assign(local(2), load(local(0))),
+        assign(place, const_int(2u32)),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ub(p, "memory access through a pointer whose borrow has been invalidated");
+}
+
+/// Raw pointers derived from a local stay usable when the local is written to directly.
+#[test]
+fn raw_ptr_survives_write() {
+    let locals = [<u32>::get_type(), <*mut u32>::get_type()];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(1), raw_addr_of(local(0))),
+        assign(local(0), const_int(0u32)),
+        assign(deref(load(local(1)), <u32>::get_type()), const_int(1u32)),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_stop(p);
+}
+
+/// Pointers cast from integers are not checked by the aliasing model.
+#[test]
+fn wildcard_write_through_shared_ref() {
+    let locals = [<u32>::get_type(), <usize>::get_type(), <*mut u32>::get_type()];
+    let shared = ref_(local(0), <u32>::get_type(), Mutability::Immutable);
+    let blocks = [
+        block!(
+            storage_live(0),
+            storage_live(1),
+            storage_live(2),
+            assign(local(0), const_int(0u32)),
+            expose_provenance(local(1), shared, 1)
+        ),
+        block!(with_exposed_provenance(local(2), load(local(1)), 2)),
+        block!(
+            assign(deref(load(local(2)), <u32>::get_type()), const_int(1u32)),
+            print(load(local(0)), 3)
+        ),
+        block!(exit()),
+    ];
+    let p = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_eq!(get_stdout(p).unwrap(), &["1"]);
+}
@@ -0,0 +1,49 @@
+use crate::*;
+
+#[test]
+fn array_repeat_works() {
+    let u8_t = <u8>::get_type();
+    let locals = [array_ty(u8_t, 4)];
+    let check = |i: u8, next| if_(eq(load(index(local(0), const_int(i))), const_int(7u8)), next, 5);
+    let blocks = [
+        block!(
+            storage_live(0),
+            assign(local(0), array_repeat(const_int(7u8), 4, u8_t)),
+            check(0, 1),
+        ),
+        block!(check(1, 2)),
+        block!(check(2, 3)),
+        block!(check(3, 4)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+    let p = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(p);
+}
+
+#[test]
+fn array_repeat_zst() {
+    let unit_t = <()>::get_type();
+    let locals = [array_ty(unit_t, 5)];
+    let stmts = [storage_live(0), assign(local(0), array_repeat(unit(), 5, unit_t))];
+    let p = small_program(&locals, &stmts);
+    assert_stop(p);
+}
+
+#[test]
+fn array_repeat_empty() {
+    let u8_t = <u8>::get_type();
+    let locals = [array_ty(u8_t, 0)];
+    let stmts = [storage_live(0), assign(local(0), array_repeat(const_int(7u8), 0, u8_t))];
+    let p = small_program(&locals, &stmts);
+    assert_stop(p);
+}
+
+/// The element count fits into `usize`, but the total size does not.
+#[test]
+fn array_repeat_too_large() {
+    let locals = [array_ty(<u16>::get_type(), usize::MAX / 2 + 1)];
+    let stmts = [storage_live(0)];
+    let p = small_program(&locals, &stmts);
+    assert_ill_formed(p, "Type: size not valid");
+}
@@ -0,0 +1,76 @@
+use crate::*;
+
+/// `struct { a: u8, b: i32 }`
+fn struct_ty() -> Type {
+    tuple_ty(&[(size(0), <u8>::get_type()), (size(4), <i32>::get_type())], size(8), align(4))
+}
+
+/// `Option<u16>`, with a separate tag byte.
+fn option_u16_ty() -> Type {
+    let u8_int = IntType { signed: Signedness::Unsigned, size: Size::from_bytes_const(1) };
+    let none = enum_variant(tuple_ty(&[], size(4), align(2)), &[(offset(0), (u8_int, 0.into()))]);
+    let some = enum_variant(
+        tuple_ty(&[(size(2), <u16>::get_type())], size(4), align(2)),
+        &[(offset(0), (u8_int, 1.into()))],
+    );
+    let discriminator = discriminator_branch::<u8>(
+        offset(0),
+        discriminator_invalid(),
+        &[((0, 1), discriminator_known(0)), ((1, 2), discriminator_known(1))],
+    );
+    enum_ty::<u8>(&[(0, none), (1, some)], discriminator, size(4), align(2))
+}
+
+#[test]
+fn assign_struct() {
+    let locals = [struct_ty()];
+    let b0 = block!(
+        storage_live(0),
+        // The fields can be given in any order.
+        assign_aggregate(
+            local(0),
+            struct_ty(),
+            &[(1, const_int::<i32>(-7)), (0, const_int::<u8>(3))]
+        ),
+        print(load(field(local(0), 0)), 1)
+    );
+    let b1 = block!(print(load(field(local(0), 1)), 2));
+    let b2 = block!(exit());
+    let p = program(&[function(Ret::No, 0, &locals, &[b0, b1, b2])]);
+    assert_eq!(get_stdout(p).unwrap(), &["3", "-7"]);
+}
+
+#[test]
+fn assign_some() {
+    let locals = [option_u16_ty()];
+    let b0 = block!(
+        storage_live(0),
+        assign_enum(local(0), option_u16_ty(), 1, &[const_int::<u16>(42)]),
+        switch_int(get_discriminant(local(0)), &[(1u8, 1)], 3)
+    );
+    let b1 = block!(print(load(field(downcast(local(0), 1), 0)), 2));
+    let b2 = block!(exit());
+    let b3 = block!(unreachable());
+    let p = program(&[function(Ret::No, 0, &locals, &[b0, b1, b2, b3])]);
+    assert_eq!(get_stdout(p).unwrap(), &["42"]);
+}
+
+#[test]
+fn assign_none() {
+    let locals = [option_u16_ty()];
+    let b0 = block!(
+        storage_live(0),
+        assign_enum(local(0), option_u16_ty(), 0, &[]),
+        switch_int(get_discriminant(local(0)), &[(0u8, 1)], 2)
+    );
+    let b1 = block!(exit());
+    let b2 = block!(unreachable());
+    let p = program(&[function(Ret::No, 0, &locals, &[b0, b1, b2])]);
+    assert_stop(p);
+}
+
+#[test]
+#[should_panic(expected = "assign_aggregate: every field must be given exactly once")]
+fn assign_struct_missing_field() {
+    assign_aggregate(local(0), struct_ty(), &[(1, const_int::<i32>(-7))]);
+}
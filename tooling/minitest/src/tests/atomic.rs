@@ -25,7 +25,7 @@ fn atomic_store_success() {
 #[test]
 fn atomic_store_arg_count() {
     let b0 = block!(Terminator::Intrinsic {
-        intrinsic: IntrinsicOp::AtomicStore,
+        intrinsic: IntrinsicOp::AtomicStore(AtomicOrdering::SeqCst),
         arguments: list!(),
         ret: zst_place(),
         next_block: Some(BbName(Name::from_internal(1)))
@@ -40,7 +40,7 @@ fn atomic_store_arg_count() {
 #[test]
 fn atomic_store_arg_type1() {
     let b0 = block!(Terminator::Intrinsic {
-        intrinsic: IntrinsicOp::AtomicStore,
+        intrinsic: IntrinsicOp::AtomicStore(AtomicOrdering::SeqCst),
         arguments: list!(const_int::<u32>(0), const_int::<u32>(0)),
         ret: zst_place(),
         next_block: Some(BbName(Name::from_internal(1)))
@@ -63,7 +63,7 @@ fn atomic_store_arg_type_pow() {
     let b0 = block!(
         storage_live(0),
         Terminator::Intrinsic {
-            intrinsic: IntrinsicOp::AtomicStore,
+            intrinsic: IntrinsicOp::AtomicStore(AtomicOrdering::SeqCst),
             arguments: list!(addr_of(local(0), ptr_ty), arr),
             ret: zst_place(),
             next_block: Some(BbName(Name::from_internal(1)))
@@ -87,7 +87,7 @@ fn atomic_store_arg_type_size() {
     let b0 = block!(
         storage_live(0),
         Terminator::Intrinsic {
-            intrinsic: IntrinsicOp::AtomicStore,
+            intrinsic: IntrinsicOp::AtomicStore(AtomicOrdering::SeqCst),
             arguments: list!(addr_of(local(0), ptr_ty), arr),
             ret: zst_place(),
             next_block: Some(BbName(Name::from_internal(1)))
@@ -109,7 +109,7 @@ fn atomic_store_ret_type() {
     let b0 = block!(
         storage_live(0),
         Terminator::Intrinsic {
-            intrinsic: IntrinsicOp::AtomicStore,
+            intrinsic: IntrinsicOp::AtomicStore(AtomicOrdering::SeqCst),
             arguments: list!(addr_of(local(0), ptr_ty), const_int::<u64>(0)),
             ret: local(0),
             next_block: Some(BbName(Name::from_internal(1)))
@@ -151,7 +151,7 @@ fn atomic_load_arg_count() {
     let b0 = block!(
         storage_live(0),
         Terminator::Intrinsic {
-            intrinsic: IntrinsicOp::AtomicLoad,
+            intrinsic: IntrinsicOp::AtomicLoad(AtomicOrdering::SeqCst),
             arguments: list!(),
             ret: local(0),
             next_block: Some(BbName(Name::from_internal(1)))
@@ -171,7 +171,7 @@ fn atomic_load_arg_type() {
     let b0 = block!(
         storage_live(0),
         Terminator::Intrinsic {
-            intrinsic: IntrinsicOp::AtomicLoad,
+            intrinsic: IntrinsicOp::AtomicLoad(AtomicOrdering::SeqCst),
             arguments: list!(unit()),
             ret: local(0),
             next_block: Some(BbName(Name::from_internal(1)))
@@ -0,0 +1,33 @@
+use crate::*;
+
+/// Prints 0, 1 and 2 in a loop.
+#[test]
+fn basic_blocks_loop() {
+    let locals = [<u32>::get_type()];
+    let bbs = basic_blocks! {
+        start => block!(storage_live(0), assign(local(0), const_int::<u32>(0)), goto(head)),
+        head => block!(print(load(local(0)), latch)),
+        latch => block!(
+            assign(local(0), add(load(local(0)), const_int::<u32>(1))),
+            if_(lt(load(local(0)), const_int::<u32>(3)), head, done),
+        ),
+        done => block!(exit()),
+    };
+    let f = function(Ret::No, 0, &locals, &bbs);
+
+    assert_eq!(get_stdout(program(&[f])).unwrap(), &["0", "1", "2"]);
+}
+
+/// The labels are the indices of the blocks.
+#[test]
+fn basic_blocks_numbering() {
+    let bbs = basic_blocks! {
+        first => block!(goto(third)),
+        second => block!(goto(first)),
+        third => block!(goto(second)),
+    };
+    for (bb, target) in bbs.iter().zip([2, 0, 1]) {
+        let Terminator::Goto(name) = bb.terminator else { panic!("not a goto") };
+        assert!(name == BbName(Name::from_internal(target)));
+    }
+}
@@ -0,0 +1,69 @@
+use crate::*;
+
+/// Runs `op` with the result place `local(0)` of type `T` and returns what it printed.
+fn run_bit_op<T: TypeConv>(op: impl FnOnce(PlaceExpr) -> Terminator) -> String {
+    let locals = [T::get_type()];
+    let b0 = block!(storage_live(0), op(local(0)));
+    let b1 = block!(print(load(local(0)), 2));
+    let b2 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    let mut out = get_stdout(program(&[f])).unwrap();
+    assert_eq!(out.len(), 1);
+    out.pop().unwrap()
+}
+
+const X: u128 = 0x8000_0000_0000_0000_0000_0000_0000_0003;
+
+#[test]
+fn reverse_bits_u128() {
+    let out = run_bit_op::<u128>(|dest| reverse_bits(dest, const_int(X), 1));
+    assert_eq!(out, X.reverse_bits().to_string());
+}
+
+#[test]
+fn reverse_bits_i128() {
+    let out = run_bit_op::<i128>(|dest| reverse_bits(dest, const_int(-2i128), 1));
+    assert_eq!(out, (-2i128).reverse_bits().to_string());
+}
+
+#[test]
+fn byte_swap_i128() {
+    let val = -0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10i128;
+    let out = run_bit_op::<i128>(|dest| byte_swap(dest, const_int(val), 1));
+    assert_eq!(out, val.swap_bytes().to_string());
+}
+
+#[test]
+fn byte_swap_u16() {
+    let out = run_bit_op::<u16>(|dest| byte_swap(dest, const_int(0x1234u16), 1));
+    assert_eq!(out, 0x3412.to_string());
+}
+
+/// The rotation amount is taken modulo the bit width.
+#[test]
+fn rotate_left_u128_wraps_amount() {
+    let out = run_bit_op::<u128>(|dest| rotate_left(dest, const_int(X), const_int(130u32), 1));
+    assert_eq!(out, X.rotate_left(130).to_string());
+    assert_eq!(out, X.rotate_left(2).to_string());
+}
+
+#[test]
+fn rotate_right_u128() {
+    let out = run_bit_op::<u128>(|dest| rotate_right(dest, const_int(X), const_int(1u32), 1));
+    assert_eq!(out, X.rotate_right(1).to_string());
+}
+
+#[test]
+fn rotate_by_width_is_identity() {
+    let out = run_bit_op::<i128>(|dest| rotate_left(dest, const_int(-5i128), const_int(128u32), 1));
+    assert_eq!(out, "-5");
+}
+
+#[test]
+fn rotate_amount_not_u32() {
+    let locals = [<u128>::get_type()];
+    let b0 = block!(storage_live(0), rotate_left(local(0), const_int(X), const_int(2u128), 1));
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_ub(program(&[f]), "invalid second argument to `RotateLeft` intrinsic: not a `u32`");
+}
@@ -0,0 +1,29 @@
+use crate::*;
+
+/// Test that `BlackBox` returns its argument unchanged.
+#[test]
+fn black_box_is_identity() {
+    let locals = [<i32>::get_type()];
+
+    let blocks = [
+        block!(storage_live(0), black_box(local(0), const_int::<i32>(5), 1)),
+        block!(if_(eq(load(local(0)), const_int::<i32>(5)), 2, 3)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(prog);
+}
+
+/// Test that `BlackBox` requires the return type to match the argument type.
+#[test]
+fn black_box_type_mismatch() {
+    let locals = [<i64>::get_type()];
+
+    let blocks =
+        [block!(storage_live(0), black_box(local(0), const_int::<i32>(5), 1)), block!(exit())];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_ub(prog, "invalid return type for `BlackBox` intrinsic: not same type as argument");
+}
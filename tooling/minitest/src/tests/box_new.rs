@@ -0,0 +1,42 @@
+use crate::*;
+
+/// Mirrors the lowering of `Box::new(5)`: allocate, turn the allocation into a box
+/// (`ShallowInitBox`, which minimize translates to a transmute), write the value, read it back.
+/// If `read_early` is set, the contents are read before they are written.
+fn box_new_program(read_early: bool) -> Program {
+    let box_i32 = box_ty(layout(size(4), align(4)));
+    let locals = [<*mut u8>::get_type(), box_i32, <i32>::get_type()];
+    let contents = || deref(load(local(1)), <i32>::get_type());
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        allocate(const_int::<usize>(4), const_int::<usize>(4), local(0), 1)
+    );
+    let mut stmts = vec![assign(local(1), transmute(load(local(0)), box_i32))];
+    if read_early {
+        stmts.push(assign(local(2), load(contents())));
+    }
+    stmts.push(assign(contents(), const_int::<i32>(5)));
+    stmts.push(assign(local(2), load(contents())));
+    let b1 = block(&stmts, print(load(local(2)), 2));
+    let b2 = block!(deallocate(load(local(0)), const_int::<usize>(4), const_int::<usize>(4), 3));
+    let b3 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+    program(&[f])
+}
+
+#[test]
+fn box_new() {
+    assert_eq!(get_stdout(box_new_program(false)).unwrap(), &["5"]);
+}
+
+#[test]
+fn box_new_read_uninit() {
+    assert_ub(
+        box_new_program(true),
+        "load at type Int(IntType { signed: Signed, size: Size(4 bytes) }) but the data in memory violates the validity invariant",
+    );
+}
@@ -0,0 +1,58 @@
+use crate::*;
+
+/// Test that `Likely` and `Unlikely` return their argument unchanged.
+#[test]
+fn branch_hint_is_identity() {
+    let locals = [<bool>::get_type()];
+    let unreach_block = 7;
+
+    let blocks = [
+        block!(storage_live(0), likely(local(0), const_bool(true), 1)),
+        block!(if_(load(local(0)), 2, unreach_block)),
+        block!(likely(local(0), const_bool(false), 3)),
+        block!(if_(load(local(0)), unreach_block, 4)),
+        block!(unlikely(local(0), const_bool(true), 5)),
+        block!(if_(load(local(0)), 6, unreach_block)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(prog);
+}
+
+/// Test that a program behaves the same with and without the hint.
+#[test]
+fn branch_hint_does_not_change_behavior() {
+    let locals = [<bool>::get_type()];
+    let with_hint = |hinted: bool| {
+        let b0 = if hinted {
+            block!(storage_live(0), unlikely(local(0), lt(const_int(1u8), const_int(2u8)), 1))
+        } else {
+            block!(storage_live(0), assign(local(0), lt(const_int(1u8), const_int(2u8))), goto(1))
+        };
+        let blocks = [
+            b0,
+            block!(if_(load(local(0)), 2, 3)),
+            block!(print(const_int::<u8>(1), 4)),
+            block!(print(const_int::<u8>(0), 4)),
+            block!(exit()),
+        ];
+        program(&[function(Ret::No, 0, &locals, &blocks)])
+    };
+
+    assert_eq!(get_stdout(with_hint(true)).unwrap(), &["1"]);
+    assert_eq!(get_stdout(with_hint(true)), get_stdout(with_hint(false)));
+}
+
+/// Test that branch hints require a Boolean.
+#[test]
+fn branch_hint_requires_bool() {
+    let locals = [<bool>::get_type()];
+
+    let blocks =
+        [block!(storage_live(0), likely(local(0), const_int::<u8>(1), 1)), block!(exit())];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_ub(prog, "invalid argument for `Likely` intrinsic: not a Boolean");
+}
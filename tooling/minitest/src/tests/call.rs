@@ -136,3 +136,20 @@ fn ret_mismatch() {
     dump_program(p);
     assert_ub(p, "call ABI violation: return types are not compatible");
 }
+
+#[test]
+fn call_add_from_function_builder() {
+    let args = [<u32>::get_type(), <u32>::get_type()];
+    let b0 = block!(assign(local(0), add(load(local(1)), load(local(2)))), return_());
+    let add_f = function_builder(&args, <u32>::get_type(), &[], &[b0]);
+
+    let locals = [<u32>::get_type()];
+    let args = [by_value(const_int(3u32)), by_value(const_int(4u32))];
+    let b0 = block!(storage_live(0), call(1, &args, local(0), Some(1)));
+    let b1 = block!(print(load(local(0)), 2));
+    let b2 = block!(exit());
+    let main_f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+
+    let out = get_stdout(program(&[main_f, add_f])).unwrap();
+    assert_eq!(out, &["7"]);
+}
@@ -0,0 +1,153 @@
+use crate::*;
+
+fn check_dereferenceable_on_load() -> RunConfig {
+    RunConfig { check_dereferenceable_on_load: true, ..RunConfig::default() }
+}
+
+fn assert_ub_with_check(prog: Program, msg: &str) {
+    assert_eq!(
+        run_program_with_config(prog, check_dereferenceable_on_load()),
+        TerminationInfo::Ub(minirust_rs::prelude::String::from_internal(msg.to_string()))
+    );
+}
+
+/// Creates a reference to local 0 in local 1, makes it dangle, and then runs `stmts`.
+fn dangling_ref_program(extra_locals: &[Type], stmts: &[Statement]) -> Program {
+    let mut locals = vec![<u32>::get_type(), <&u32>::get_type()];
+    locals.extend_from_slice(extra_locals);
+    let mut b0 = vec![
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), const_int(7u32)),
+        assign(local(1), ref_(local(0), <u32>::get_type(), Mutability::Immutable)),
+        storage_dead(0),
+    ];
+    for i in 2..locals.len() {
+        b0.push(storage_live(i as u32));
+    }
+    b0.extend_from_slice(stmts);
+    let b0 = block(&b0, exit());
+    let f = function(Ret::No, 0, &locals, &[b0]);
+    program(&[f])
+}
+
+/// `Option<&u32>`, using the null pointer as niche for `None`.
+fn option_ref_ty() -> Type {
+    let u64_int = IntType { signed: Signedness::Unsigned, size: size(8) };
+    let none_ty = tuple_ty(&[], size(8), align(8));
+    let some_ty = tuple_ty(&[(offset(0), <&u32>::get_type())], size(8), align(8));
+    let none = enum_variant(none_ty, &[(offset(0), (u64_int, 0.into()))]);
+    let some = enum_variant(some_ty, &[]);
+    enum_ty::<u8>(
+        &[(0, none), (1, some)],
+        discriminator_branch::<u64>(
+            offset(0),
+            discriminator_known(1),
+            &[((0, 1), discriminator_known(0))],
+        ),
+        size(8),
+        align(8),
+    )
+}
+
+/// Copying a dangling reference is fine, unless loads check validity.
+#[test]
+fn copy_dangling_ref() {
+    let prog = dangling_ref_program(&[<&u32>::get_type()], &[assign(local(2), load(local(1)))]);
+    assert_stop(prog);
+    assert_ub_with_check(prog, "loaded a safe pointer that is not dereferenceable for 4 bytes");
+}
+
+/// The check also looks into enums: loading a `Some` with a dangling reference is caught.
+#[test]
+fn copy_option_dangling_ref() {
+    let some_ty = tuple_ty(&[(offset(0), <&u32>::get_type())], size(8), align(8));
+    let some = variant(1, tuple(&[load(local(1))], some_ty), option_ref_ty());
+    let prog = dangling_ref_program(
+        &[option_ref_ty(), option_ref_ty()],
+        &[assign(local(2), some), assign(local(3), load(local(2)))],
+    );
+    assert_stop(prog);
+    assert_ub_with_check(prog, "loaded a safe pointer that is not dereferenceable for 4 bytes");
+}
+
+/// A `None` contains no reference, so there is nothing to check.
+#[test]
+fn copy_option_none() {
+    let none_ty = tuple_ty(&[], size(8), align(8));
+    let none = variant(0, tuple(&[], none_ty), option_ref_ty());
+    let prog = dangling_ref_program(
+        &[option_ref_ty(), option_ref_ty()],
+        &[assign(local(2), none), assign(local(3), load(local(2)))],
+    );
+    assert_eq!(
+        run_program_with_config(prog, check_dereferenceable_on_load()),
+        TerminationInfo::MachineStop(Int::ZERO)
+    );
+}
+
+/// `enum E { A(u32, &u32), B }`, using the null pointer at offset 8 as niche for `B`.
+fn niche_at_offset_ty() -> Type {
+    let u64_int = IntType { signed: Signedness::Unsigned, size: size(8) };
+    let a_ty = tuple_ty(
+        &[(offset(0), <u32>::get_type()), (offset(8), <&u32>::get_type())],
+        size(16),
+        align(8),
+    );
+    let b_ty = tuple_ty(&[], size(16), align(8));
+    let a = enum_variant(a_ty, &[]);
+    let b = enum_variant(b_ty, &[(offset(8), (u64_int, 0.into()))]);
+    enum_ty::<u8>(
+        &[(0, a), (1, b)],
+        discriminator_branch::<u64>(
+            offset(8),
+            discriminator_known(0),
+            &[((0, 1), discriminator_known(1))],
+        ),
+        size(16),
+        align(8),
+    )
+}
+
+/// An enum whose niche holds a dangling reference decodes fine, so loading it is only UB when
+/// loads check dereferenceability.
+#[test]
+fn copy_niche_enum_dangling_ref() {
+    let a_ty = tuple_ty(
+        &[(offset(0), <u32>::get_type()), (offset(8), <&u32>::get_type())],
+        size(16),
+        align(8),
+    );
+    let a = variant(0, tuple(&[const_int(1u32), load(local(1))], a_ty), niche_at_offset_ty());
+    let prog = dangling_ref_program(
+        &[niche_at_offset_ty(), niche_at_offset_ty()],
+        &[assign(local(2), a), assign(local(3), load(local(2)))],
+    );
+    assert_stop(prog);
+    assert_ub_with_check(prog, "loaded a safe pointer that is not dereferenceable for 4 bytes");
+}
+
+/// A misaligned reference violates the validity invariant, so loading an enum holding one in its
+/// niche is UB even without the flag.
+#[test]
+fn load_niche_enum_misaligned_ref() {
+    let locals = [<[u64; 2]>::get_type(), niche_at_offset_ty()];
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(index(local(0), const_int(0usize)), const_int(0u64)),
+        assign(index(local(0), const_int(1usize)), const_int(3u64)),
+        assign(
+            local(1),
+            load(deref(addr_of(local(0), <*const u64>::get_type()), niche_at_offset_ty()))
+        ),
+        exit(),
+    );
+    let prog = program(&[function(Ret::No, 0, &locals, &[b0])]);
+    let msg = format!(
+        "load at type {:?} but the data in memory violates the validity invariant",
+        niche_at_offset_ty()
+    );
+    assert_ub(prog, &msg);
+    assert_ub_with_check(prog, &msg);
+}
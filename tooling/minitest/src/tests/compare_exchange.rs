@@ -183,3 +183,47 @@ fn compare_exchange_arg_size_max() {
     let p = program(&[f]);
     assert_ub(p, "invalid return type for `AtomicCompareExchange` intrinsic: size too big");
 }
+
+/// Runs a CAS from `current` to 42 on a location holding 1, and prints the previous value and
+/// whether the exchange happened.
+fn compare_exchange_val_program(current: u32) -> Program {
+    let locals = [<u32>::get_type(), <u32>::get_type(), <bool>::get_type()];
+    let addr0 = addr_of(local(0), raw_ptr_ty());
+
+    let (cas, ok) = compare_exchange_val(
+        local(1),
+        local(2),
+        addr0,
+        const_int::<u32>(current),
+        const_int::<u32>(42),
+        1,
+    );
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        assign(local(0), const_int::<u32>(1)),
+        cas,
+    );
+    let b1 = block!(ok, print(load(local(1)), 2));
+    let b2 = block!(print(load(local(2)), 3));
+    let b3 = block!(print(load(local(0)), 4));
+    let b4 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4]);
+    program(&[f])
+}
+
+#[test]
+fn compare_exchange_val_success() {
+    let out = get_stdout(compare_exchange_val_program(1)).unwrap();
+    // The previous value equals `current`, and the new value got stored.
+    assert_eq!(out, &["1", "true", "42"]);
+}
+
+#[test]
+fn compare_exchange_val_failure() {
+    let out = get_stdout(compare_exchange_val_program(3)).unwrap();
+    // The previous value differs from `current`, and nothing got stored.
+    assert_eq!(out, &["1", "false", "1"]);
+}
@@ -0,0 +1,27 @@
+use crate::*;
+
+fn assign_const<T: TypeConv>(val: impl Into<Int>) -> Program {
+    let locals = [T::get_type()];
+    let stmts = [storage_live(0), assign(local(0), const_int_typed::<T>(val.into()))];
+    small_program(&locals, &stmts)
+}
+
+#[test]
+fn unsigned_out_of_range() {
+    assert_ill_formed(assign_const::<u8>(300), "Constant::Int: value out of range for type");
+    assert_ill_formed(assign_const::<u8>(-1), "Constant::Int: value out of range for type");
+}
+
+#[test]
+fn signed_out_of_range() {
+    assert_ill_formed(assign_const::<i8>(128), "Constant::Int: value out of range for type");
+    assert_ill_formed(assign_const::<i8>(-129), "Constant::Int: value out of range for type");
+}
+
+#[test]
+fn boundaries_fit() {
+    assert_stop(assign_const::<u8>(0));
+    assert_stop(assign_const::<u8>(255));
+    assert_stop(assign_const::<i8>(-128));
+    assert_stop(assign_const::<i8>(127));
+}
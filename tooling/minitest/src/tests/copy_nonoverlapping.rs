@@ -0,0 +1,61 @@
+use crate::*;
+
+/// A pointer to byte `i` of local 0, which is an array of `u8`.
+fn byte_ptr(i: usize) -> ValueExpr {
+    addr_of(index(local(0), const_int::<usize>(i)), <*mut u8>::get_type())
+}
+
+/// Copies `count` bytes from `src` to `dst`, then prints both bytes of local 0.
+fn copy_program(src: ValueExpr, dst: ValueExpr, count: usize) -> Program {
+    let locals = [<[u8; 2]>::get_type()];
+    let b0 = block!(
+        storage_live(0),
+        assign(index(local(0), const_int::<usize>(0)), const_int::<u8>(1)),
+        assign(index(local(0), const_int::<usize>(1)), const_int::<u8>(2)),
+        copy_nonoverlapping(src, dst, const_int::<usize>(count), <u8>::get_type(), 1),
+    );
+    let b1 = block!(print(load(index(local(0), const_int::<usize>(0))), 2));
+    let b2 = block!(print(load(index(local(0), const_int::<usize>(1))), 3));
+    let b3 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+    program(&[f])
+}
+
+#[test]
+fn copy_adjacent() {
+    let p = copy_program(byte_ptr(0), byte_ptr(1), 1);
+    assert_eq!(get_stdout(p).unwrap(), &["1", "1"]);
+}
+
+#[test]
+fn copy_zero_length_self() {
+    let p = copy_program(byte_ptr(0), byte_ptr(0), 0);
+    assert_eq!(get_stdout(p).unwrap(), &["1", "2"]);
+}
+
+#[test]
+fn copy_zero_length_dangling() {
+    let dangling = transmute(const_int::<usize>(16), <*mut u8>::get_type());
+    let p = copy_program(dangling, dangling, 0);
+    assert_eq!(get_stdout(p).unwrap(), &["1", "2"]);
+}
+
+#[test]
+fn copy_exact_overlap() {
+    let p = copy_program(byte_ptr(1), byte_ptr(1), 1);
+    assert_ub(p, "`CopyNonOverlapping` intrinsic called on overlapping ranges");
+}
+
+#[test]
+fn copy_partial_overlap() {
+    // Copying bytes 0 and 1 to bytes 1 and 2 of a `[u8; 4]`.
+    let locals = [<[u8; 4]>::get_type()];
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), transmute(const_int::<u32>(0), <[u8; 4]>::get_type())),
+        copy_nonoverlapping(byte_ptr(0), byte_ptr(1), const_int::<usize>(2), <u8>::get_type(), 1),
+    );
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_ub(program(&[f]), "`CopyNonOverlapping` intrinsic called on overlapping ranges");
+}
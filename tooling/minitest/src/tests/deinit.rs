@@ -0,0 +1,32 @@
+use crate::*;
+
+fn setup() -> [Statement; 3] {
+    [storage_live(0), storage_live(1), assign(local(0), const_int(42u32))]
+}
+
+#[test]
+fn deinit_then_read() {
+    let locals = [<u32>::get_type(); 2];
+    let stmts = [&setup()[..], &[deinit(local(0)), assign(local(1), load(local(0)))]].concat();
+    let p = small_program(&locals, &stmts);
+    assert_ub(
+        p,
+        "load at type Int(IntType { signed: Unsigned, size: Size(4 bytes) }) but the data in memory violates the validity invariant",
+    );
+}
+
+#[test]
+fn deinit_rewrite_read() {
+    let locals = [<u32>::get_type(); 2];
+    let stmts = [
+        &setup()[..],
+        &[
+            deinit(local(0)),
+            assign(local(0), const_int(7u32)),
+            assign(local(1), load(local(0))),
+        ],
+    ]
+    .concat();
+    let p = small_program(&locals, &stmts);
+    assert_stop(p);
+}
@@ -39,7 +39,7 @@ fn validate_dangling_ref() {
     let b0 = block!(
         storage_live(0),
         assign(local(0), dangling_ref),
-        validate(local(0), /* fn_entry */ false),
+        validate(local(0), RetagKind::Default),
         exit(),
     );
     let f = function(Ret::No, 0, &locals, &[b0]);
@@ -80,3 +80,63 @@ fn ref_dangling_ptr() {
     let p = program(&[f]);
     assert_ub(p, "non-zero-sized access with invalid pointer");
 }
+
+/// Test that `&mut local` works for a live, aligned local.
+#[test]
+fn ref_mut_live_local() {
+    let locals = [<i32>::get_type(), <&mut i32>::get_type()];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(1), ref_(local(0), <i32>::get_type(), Mutability::Mutable)),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_stop(p);
+}
+
+/// Test that `&mut *dangling_ptr` is UB at creation time.
+#[test]
+fn ref_mut_dangling() {
+    let locals = [<&mut i32>::get_type()];
+    let dangling_ptr = transmute(const_int(16usize), <*mut i32>::get_type());
+    let place = deref(dangling_ptr, <i32>::get_type());
+    let stmts =
+        [storage_live(0), assign(local(0), ref_(place, <i32>::get_type(), Mutability::Mutable))];
+    let p = small_program(&locals, &stmts);
+    assert_ub(p, "non-zero-sized access with invalid pointer");
+}
+
+/// Test that a reference covering more than its allocation is UB at creation time.
+#[test]
+fn ref_out_of_bounds() {
+    let locals = [<*const i32>::get_type(), <&i32>::get_type()];
+    // The allocation is aligned for `i32`, but too small for it.
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        allocate(const_int(2usize), const_int(4usize), local(0), 1),
+    );
+    let place = deref(load(local(0)), <i32>::get_type());
+    let b1 = block!(
+        assign(local(1), ref_(place, <i32>::get_type(), Mutability::Immutable)),
+        exit(),
+    );
+    let p = program(&[function(Ret::No, 0, &locals, &[b0, b1])]);
+    assert_ub(p, "out-of-bounds memory access");
+}
+
+/// Test that a reference to a misaligned place is UB at creation time.
+#[test]
+fn ref_mut_misaligned() {
+    let locals = [<[u32; 2]>::get_type(), <&mut u32>::get_type()];
+    let base = addr_of(index(local(0), const_int(0usize)), <*mut u32>::get_type());
+    let misaligned = ptr_offset(base, const_int(1usize), InBounds::Yes);
+    let place = deref(misaligned, <u32>::get_type());
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(1), ref_(place, <u32>::get_type(), Mutability::Mutable)),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ub(p, "taking the address of an invalid (null, misaligned, or uninhabited) place");
+}
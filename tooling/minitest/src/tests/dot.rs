@@ -0,0 +1,30 @@
+use crate::*;
+
+#[test]
+fn dot_branchy_function() {
+    let locals = [<u8>::get_type()];
+    let b0 = block!(storage_live(0), assign(local(0), const_int::<u8>(1)), goto(1));
+    let b1 = block!(switch_int(load(local(0)), &[(0u8, 2), (1u8, 3)], 4));
+    let b2 = block!(print(const_int::<u8>(0), 4));
+    let b3 = block!(if_(const_bool(true), 4, 5));
+    let b4 = block!(exit());
+    let b5 = block!(unreachable());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5]);
+
+    let dot = to_dot(f);
+    assert!(dot.starts_with("digraph cfg {"));
+    // Node lines look like `bb0 [label=...]`, edge lines like `bb0 -> bb1 [label=...]`.
+    let is_edge = |line: &str| line.split_whitespace().nth(1) == Some("->");
+    let nodes = dot.lines().filter(|l| l.starts_with("  bb") && !is_edge(l)).count();
+    let edges = dot.lines().filter(|l| is_edge(l)).count();
+    assert_eq!(nodes, 6);
+    // b0: 1, b1: 3, b2: 1, b3: 2, b4 and b5: none.
+    assert_eq!(edges, 7);
+    assert!(dot.contains("bb0 -> bb1 [label=\"goto\"]"));
+    assert!(dot.contains("bb1 -> bb3 [label=\"1\"]"));
+    assert!(dot.contains("bb1 -> bb4 [label=\"otherwise\"]"));
+    assert!(dot.contains("bb2 -> bb4 [label=\"return\"]"));
+    assert!(dot.contains("bb3 -> bb5 [label=\"otherwise\"]"));
+    // The start block is highlighted.
+    assert_eq!(dot.matches("style=bold").count(), 1);
+}
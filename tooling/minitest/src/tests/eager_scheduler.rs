@@ -0,0 +1,72 @@
+use crate::*;
+
+fn eager() -> RunConfig {
+    RunConfig { scheduler: SchedulerMode::Eager, ..RunConfig::default() }
+}
+
+/// Spawns a thread that writes 42 to the `u32` behind its data pointer, joins it, and then
+/// prints that `u32`.
+#[test]
+fn eager_spawn_join_value() {
+    let locals = [<u32>::get_type(), <u32>::get_type()];
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        spawn(fn_ptr(1), addr_of(local(0), <*mut u32>::get_type()), local(1), 1),
+    );
+    let b1 = block!(join(load(local(1)), 2));
+    let b2 = block!(print(load(local(0)), 3));
+    let b3 = block!(exit());
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+
+    let locals = [<*mut u32>::get_type()];
+    let b0 = block!(
+        assign(deref(load(local(0)), <u32>::get_type()), const_int::<u32>(42)),
+        return_(),
+    );
+    let writer = function(Ret::No, 1, &locals, &[b0]);
+
+    let p = program(&[main, writer]);
+    assert_eq!(get_stdout_with_config(p, eager()).unwrap(), &["42"]);
+}
+
+/// The spawned thread runs to completion before its parent continues, even without a `Join`.
+#[test]
+fn eager_runs_spawned_thread_first() {
+    let locals = [<u32>::get_type()];
+    let b0 = block!(storage_live(0), spawn(fn_ptr(1), null(), local(0), 1));
+    let b1 = block!(print(const_int::<u32>(2), 2));
+    let b2 = block!(join(load(local(0)), 3));
+    let b3 = block!(exit());
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+
+    let b0 = block!(print(const_int::<u32>(1), 1));
+    let b1 = block!(return_());
+    let printer = function(Ret::No, 1, &[<*const ()>::get_type()], &[b0, b1]);
+
+    let p = program(&[main, printer]);
+    for _ in 0..20 {
+        assert_eq!(get_stdout_with_config(p, eager()).unwrap(), &["1", "2"]);
+    }
+}
+
+/// UB in the spawned thread is still detected.
+#[test]
+fn eager_ub_in_spawned_thread() {
+    let locals = [<u32>::get_type()];
+    let b0 = block!(storage_live(0), spawn(fn_ptr(1), null(), local(0), 1));
+    let b1 = block!(join(load(local(0)), 2));
+    let b2 = block!(exit());
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+
+    let b0 = block!(unreachable());
+    let ub = function(Ret::No, 1, &[<*const ()>::get_type()], &[b0]);
+
+    let p = program(&[main, ub]);
+    assert_eq!(
+        run_program_with_config(p, eager()),
+        TerminationInfo::Ub(minirust_rs::prelude::String::from_internal(
+            "reached unreachable code".to_string()
+        ))
+    );
+}
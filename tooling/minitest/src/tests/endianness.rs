@@ -0,0 +1,31 @@
+use crate::*;
+
+/// Transmutes `0x01020304_u32` to `[u8; 4]` and prints the bytes in memory order.
+fn print_u32_bytes() -> Program {
+    let locals = [<[u8; 4]>::get_type()];
+
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), transmute(const_int::<u32>(0x01020304), <[u8; 4]>::get_type())),
+        print(load(index(local(0), const_int::<usize>(0))), 1)
+    );
+    let b1 = block!(print(load(index(local(0), const_int::<usize>(1))), 2));
+    let b2 = block!(print(load(index(local(0), const_int::<usize>(2))), 3));
+    let b3 = block!(print(load(index(local(0), const_int::<usize>(3))), 4));
+    let b4 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4]);
+    program(&[f])
+}
+
+#[test]
+fn u32_bytes_little_endian() {
+    let out = get_stdout_for_target::<x86_64>(print_u32_bytes()).unwrap();
+    assert_eq!(out, &["4", "3", "2", "1"]);
+}
+
+#[test]
+fn u32_bytes_big_endian() {
+    let out = get_stdout_for_target::<aarch64_be>(print_u32_bytes()).unwrap();
+    assert_eq!(out, &["1", "2", "3", "4"]);
+}
@@ -351,3 +351,53 @@ fn space_optimized_enum_works() {
     let program = program(&[function(Ret::No, 0, &locals, &blocks)]);
     assert_stop(program);
 }
+
+/// Ensures that the discriminant of a niche-encoded enum is the logical discriminant rather than
+/// the tag, so that values of the same variant have the same discriminant.
+#[test]
+fn niche_discriminant_is_logical() {
+    let u8_t = int_ty(Signedness::Unsigned, size(1));
+    let some_ty = tuple_ty(&[(offset(0), u8_t)], size(1), align(1));
+    // `Option<NonZeroU8>`, with `None` (discriminant 0) encoded as tag 0.
+    let enum_ty = enum_ty::<u8>(
+        &[
+            (
+                0,
+                enum_variant(
+                    tuple_ty(&[], size(1), align(1)),
+                    &[(offset(0), (U8_INTTYPE, 0.into()))],
+                ),
+            ),
+            (1, enum_variant(some_ty, &[])),
+        ],
+        discriminator_branch::<u8>(
+            offset(0),
+            discriminator_known(1),
+            &[((0, 1), discriminator_known(0))],
+        ),
+        size(1),
+        align(1),
+    );
+    let some = |x: u8| variant(1, tuple(&[const_int(x)], some_ty), enum_ty);
+    let none = variant(0, tuple(&[], tuple_ty(&[], size(1), align(1))), enum_ty);
+
+    let locals = [enum_ty, enum_ty, enum_ty];
+    let blocks = [
+        block!(
+            storage_live(0),
+            storage_live(1),
+            storage_live(2),
+            assign(local(0), some(5)),
+            assign(local(1), some(7)),
+            assign(local(2), none),
+            // The discriminant of `Some` is 1, even though the tag byte is 5.
+            if_(eq(get_discriminant(local(0)), const_int(1u8)), 1, 4),
+        ),
+        block!(if_(eq(get_discriminant(local(0)), get_discriminant(local(1))), 2, 4)),
+        block!(if_(eq(get_discriminant(local(0)), get_discriminant(local(2))), 4, 3)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+    let program = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(program);
+}
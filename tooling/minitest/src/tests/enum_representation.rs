@@ -328,3 +328,81 @@ fn negative_discriminants_work() {
     let prog = small_program(locals, statements);
     assert_stop(prog)
 }
+
+/// Works: constructing both variants of an `Option<u32>` and reading them back.
+#[test]
+fn option_u32_roundtrip() {
+    let u32_t = <u32>::get_type();
+    let none_ty = tuple_ty(&[], size(8), align(4));
+    let some_ty = tuple_ty(&[(offset(4), u32_t)], size(8), align(4));
+    let enum_ty = enum_ty::<u32>(
+        &[
+            (0, enum_variant(none_ty, &[(offset(0), (U32_INTTYPE, 0.into()))])),
+            (1, enum_variant(some_ty, &[(offset(0), (U32_INTTYPE, 1.into()))])),
+        ],
+        discriminator_branch::<u32>(
+            offset(0),
+            discriminator_invalid(),
+            &[((0, 1), discriminator_known(0)), ((1, 2), discriminator_known(1))],
+        ),
+        size(8),
+        align(4),
+    );
+
+    let locals = &[enum_ty, enum_ty];
+    let blocks = [
+        block!(
+            storage_live(0),
+            storage_live(1),
+            assign(local(0), variant(1, tuple(&[const_int(3u32)], some_ty), enum_ty)),
+            assign(local(1), variant(0, tuple(&[], none_ty), enum_ty)),
+            if_(eq(get_discriminant(local(0)), const_int(1u32)), 1, 4),
+        ),
+        block!(if_(eq(load(field(downcast(local(0), 1), 0)), const_int(3u32)), 2, 4)),
+        block!(if_(eq(get_discriminant(local(1)), const_int(0u32)), 3, 4)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+    let prog = program(&[function(Ret::No, 0, locals, &blocks)]);
+    assert_stop(prog)
+}
+
+/// Works: the untagged variant of a niche-encoded `Option<bool>` writes no tag,
+/// its data alone puts the byte outside of the niche.
+#[test]
+fn niche_untagged_variant_roundtrip() {
+    let some_ty = tuple_ty(&[(offset(0), Type::Bool)], size(1), align(1));
+    let none_ty = tuple_ty(&[], size(1), align(1));
+    let enum_ty = enum_ty::<u8>(
+        &[
+            (0, enum_variant(none_ty, &[(offset(0), (U8_INTTYPE, 2.into()))])),
+            (1, enum_variant(some_ty, &[])),
+        ],
+        discriminator_branch::<u8>(
+            offset(0),
+            discriminator_invalid(),
+            &[((0, 2), discriminator_known(1)), ((2, 3), discriminator_known(0))],
+        ),
+        size(1),
+        align(1),
+    );
+    let union_ty =
+        union_ty(&[(offset(0), enum_ty), (offset(0), <u8>::get_type())], size(1), align(1));
+    let locals = &[union_ty];
+    let blocks = [
+        block!(
+            storage_live(0),
+            assign(field(local(0), 0), variant(1, tuple(&[const_bool(true)], some_ty), enum_ty)),
+            if_(eq(load(field(local(0), 1)), const_int(1u8)), 1, 4),
+        ),
+        block!(if_(eq(get_discriminant(field(local(0), 0)), const_int(1u8)), 2, 4)),
+        block!(
+            assign(field(local(0), 0), variant(0, tuple(&[], none_ty), enum_ty)),
+            if_(eq(load(field(local(0), 1)), const_int(2u8)), 3, 4),
+        ),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+    let prog = program(&[function(Ret::No, 0, locals, &blocks)]);
+    assert_stop(prog)
+}
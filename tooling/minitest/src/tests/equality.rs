@@ -0,0 +1,53 @@
+use crate::*;
+
+/// `Option<u8>`-like enum with the given tag for `None`.
+fn option_u8_ty(none_tag: u8) -> Type {
+    let u8_int = IntType { signed: Signedness::Unsigned, size: Size::from_bytes_const(1) };
+    let none =
+        enum_variant(tuple_ty(&[], size(2), align(1)), &[(offset(0), (u8_int, none_tag.into()))]);
+    let some = enum_variant(
+        tuple_ty(&[(size(1), <u8>::get_type())], size(2), align(1)),
+        &[(offset(0), (u8_int, 1.into()))],
+    );
+    let discriminator = discriminator_branch::<u8>(
+        offset(0),
+        discriminator_invalid(),
+        &[((none_tag, none_tag + 1), discriminator_known(0)), ((1, 2), discriminator_known(1))],
+    );
+    enum_ty::<u8>(&[(0, none), (1, some)], discriminator, size(2), align(1))
+}
+
+/// Types, and everything containing them, are compared structurally:
+/// their parts live behind `GcCow`s, but two separately built types are still equal.
+/// The derived `Hash` does not agree with that, so we hash with `type_hash` instead.
+#[test]
+fn type_eq_structural() {
+    let a = option_u8_ty(0);
+    let b = option_u8_ty(0);
+    assert_eq!(a, b);
+    assert_eq!(type_hash(a), type_hash(b));
+
+    assert_ne!(a, option_u8_ty(2));
+}
+
+#[test]
+fn program_eq_structural() {
+    let build = |val: i32| {
+        let locals = [<i32>::get_type(), option_u8_ty(0)];
+        let b0 = block!(storage_live(0), assign(local(0), const_int::<i32>(val)), goto(1));
+        let b1 = block!(if_(eq(load(local(0)), const_int::<i32>(2)), 2, 2));
+        let b2 = block!(exit());
+        program(&[function(Ret::No, 0, &locals, &[b0, b1, b2])])
+    };
+
+    let a = build(1);
+    let b = build(1);
+    assert_eq!(a, b);
+    assert_eq!(program_hash(a), program_hash(b));
+    let block_1 = |p: Program| {
+        p.functions.get(p.start).unwrap().blocks.get(BbName(Name::from_internal(1))).unwrap()
+    };
+    assert_eq!(block_1(a).terminator, block_1(b).terminator);
+
+    assert_ne!(a, build(2));
+}
@@ -0,0 +1,52 @@
+use crate::*;
+
+fn test_intrinsics() -> RunConfig {
+    RunConfig { test_intrinsics: true, ..RunConfig::default() }
+}
+
+/// Calls itself with `n - 1` until `n` is 0, and then prints the frame depth.
+fn recurse() -> Function {
+    let locals = [<()>::get_type(), <u32>::get_type(), <()>::get_type(), <usize>::get_type()];
+    let bbs = basic_blocks! {
+        start => block!(if_(eq(load(local(1)), const_int(0u32)), bottom, go_down)),
+        go_down => block!(
+            storage_live(2),
+            call(1, &[by_value(sub(load(local(1)), const_int(1u32)))], local(2), Some(done)),
+        ),
+        done => block!(storage_dead(2), return_()),
+        bottom => block!(storage_live(3), frame_depth(local(3), print_depth)),
+        print_depth => block!(print(load(local(3)), bottom_done)),
+        bottom_done => block!(return_()),
+    };
+    function(Ret::Yes, 1, &locals, &bbs)
+}
+
+/// Calls `recurse` with `n`.
+fn recurse_program(n: u32) -> Program {
+    let locals = [<()>::get_type()];
+    let b0 = block!(storage_live(0), call(1, &[by_value(const_int(n))], local(0), Some(1)));
+    let b1 = block!(exit());
+    let main = function(Ret::No, 0, &locals, &[b0, b1]);
+    program(&[main, recurse()])
+}
+
+#[test]
+fn frame_depth_at_bottom() {
+    // `main` and the 6 frames of `recurse` for `n` from 5 to 0.
+    let out = get_stdout_with_config(recurse_program(5), test_intrinsics()).unwrap();
+    assert_eq!(out, &["7"]);
+}
+
+/// The stack limit allows exactly as many frames as `FrameDepth` reports.
+#[test]
+fn frame_depth_stack_limit() {
+    let config = RunConfig { stack_limit: Some(7), ..test_intrinsics() };
+    assert_eq!(get_stdout_with_config(recurse_program(5), config).unwrap(), &["7"]);
+    let info = run_program_with_config(recurse_program(6), config);
+    assert_eq!(info, TerminationInfo::StackOverflow);
+}
+
+#[test]
+fn frame_depth_needs_test_intrinsics() {
+    assert_ub(recurse_program(0), "`FrameDepth` intrinsic used without enabling test intrinsics");
+}
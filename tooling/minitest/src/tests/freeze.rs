@@ -0,0 +1,66 @@
+use crate::*;
+
+/// Freezes local 0 of type `u8` into local 2, and prints `local(2) & 0` and `local(2) - local(2)`.
+fn freeze_program(init: Option<u8>) -> Program {
+    let locals = [<u8>::get_type(), <*const u8>::get_type(), <u8>::get_type()];
+    let mut stmts = vec![storage_live(0), storage_live(1), storage_live(2)];
+    if let Some(init) = init {
+        stmts.push(assign(local(0), const_int::<u8>(init)));
+    }
+    stmts.push(assign(local(1), raw_addr_of(local(0))));
+    let b0 = block(&stmts, freeze(local(2), load(local(1)), 1));
+    let b1 = block!(print(bit_and(load(local(2)), const_int::<u8>(0)), 2));
+    // The frozen value is fixed, so it is equal to itself.
+    let b2 = block!(print(sub(load(local(2)), load(local(2))), 3));
+    let b3 = block!(print(load(local(2)), 4));
+    let b4 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4]);
+    program(&[f])
+}
+
+#[test]
+fn freeze_uninit() {
+    let out = get_stdout(freeze_program(None)).unwrap();
+    assert_eq!(out[..2], ["0", "0"]);
+}
+
+/// With a seed, the frozen bytes are the same in every run.
+#[test]
+fn freeze_uninit_seeded() {
+    let config = RunConfig { freeze_seed: Some(7), ..RunConfig::default() };
+    let out = get_stdout_with_config(freeze_program(None), config).unwrap();
+    for _ in 0..10 {
+        assert_eq!(get_stdout_with_config(freeze_program(None), config).unwrap(), out);
+    }
+}
+
+#[test]
+fn freeze_init() {
+    assert_eq!(get_stdout(freeze_program(Some(42))).unwrap(), &["0", "0", "42"]);
+}
+
+/// Without `freeze`, using the uninitialized value is UB.
+#[test]
+fn uninit_arithmetic() {
+    let locals = [<u8>::get_type(), <u8>::get_type()];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(1), bit_and(load(local(0)), const_int::<u8>(0))),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ub(
+        p,
+        "load at type Int(IntType { signed: Unsigned, size: Size(1 bytes) }) but the data in memory violates the validity invariant",
+    );
+}
+
+#[test]
+fn freeze_wrong_arg() {
+    let locals = [<u8>::get_type()];
+    let b0 = block!(storage_live(0), freeze(local(0), const_int::<u8>(0), 1));
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    let p = program(&[f]);
+    assert_ub(p, "invalid argument for `Freeze` intrinsic: not a pointer");
+}
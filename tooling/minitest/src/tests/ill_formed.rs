@@ -52,3 +52,169 @@ fn type_mismatch() {
     let p = small_program(locals, stmts);
     assert_ill_formed(p, "Statement::Assign: destination and source type differ");
 }
+
+#[test]
+fn goto_nonexistent_block() {
+    let b0 = block!(goto(1));
+    let b1 = block!(goto(7)); // there is no block 7
+    let f = function(Ret::No, 0, &[], &[b0, b1]);
+    let p = program(&[f]);
+    assert_ill_formed(p, "Terminator: jump to nonexistent block");
+}
+
+#[test]
+fn switch_to_nonexistent_block() {
+    let b0 = block!(switch_int(const_int::<u8>(0), &[(0u8, 1)], 3));
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &[], &[b0, b1]);
+    let p = program(&[f]);
+    assert_ill_formed(p, "Terminator: jump to nonexistent block");
+}
+
+#[test]
+fn storage_live_undeclared_local() {
+    let locals = &[<bool>::get_type()];
+    let stmts = &[storage_live(3)]; // only local 0 (and the unit return local 1) are declared
+    let p = small_program(locals, stmts);
+    assert_ill_formed(p, "Statement::StorageLive: invalid local variable");
+}
+
+#[test]
+fn use_undeclared_local() {
+    let locals = &[<bool>::get_type()];
+    let stmts = &[storage_live(0), assign(local(0), load(local(3)))];
+    let p = small_program(locals, stmts);
+    assert_ill_formed(p, "PlaceExpr::Local: unknown local name");
+}
+
+#[test]
+fn start_fn_with_args() {
+    let b0 = block!(exit());
+    let f = function(Ret::No, 1, &[<u8>::get_type()], &[b0]);
+    let p = program(&[f]);
+    assert_ill_formed(p, "Program: supplied start function with arguments");
+}
+
+#[test]
+fn start_fn_non_unit_return() {
+    let b0 = block!(exit());
+    let f = function(Ret::Yes, 0, &[<u32>::get_type()], &[b0]);
+    let p = program(&[f]);
+    assert_ill_formed(p, "Program: start function return local has invalid layout");
+}
+
+/// A function taking a `u8` and returning a `u32`, which runs `stmts` and returns.
+/// Local 0 is the return local and local 1 the argument.
+fn with_arg_and_ret(stmts: &[Statement]) -> Program {
+    let locals = [<u32>::get_type(), <u8>::get_type()];
+    let callee = function(Ret::Yes, 1, &locals, &[block(stmts, return_())]);
+
+    let main_locals = [<u32>::get_type()];
+    let b0 = block!(
+        storage_live(0),
+        call(1, &[by_value(const_int::<u8>(0))], local(0), Some(1))
+    );
+    let b1 = block!(exit());
+    let main = function(Ret::No, 0, &main_locals, &[b0, b1]);
+
+    program(&[main, callee])
+}
+
+#[test]
+fn storage_live_arg() {
+    let p = with_arg_and_ret(&[storage_live(1)]);
+    assert_ill_formed(p, "Statement::StorageLive: cannot apply to argument/return local");
+}
+
+#[test]
+fn storage_live_ret() {
+    let p = with_arg_and_ret(&[storage_live(0)]);
+    assert_ill_formed(p, "Statement::StorageLive: cannot apply to argument/return local");
+}
+
+#[test]
+fn storage_dead_arg() {
+    let p = with_arg_and_ret(&[storage_dead(1)]);
+    assert_ill_formed(
+        p,
+        "Statement::StorageDead: trying to mark argument or return local as dead",
+    );
+}
+
+#[test]
+fn storage_dead_ret() {
+    let p = with_arg_and_ret(&[storage_dead(0)]);
+    assert_ill_formed(
+        p,
+        "Statement::StorageDead: trying to mark argument or return local as dead",
+    );
+}
+
+/// Calls a function returning `u32` whose body consists of `blocks`.
+fn call_u32_fn(blocks: &[BasicBlock]) -> Program {
+    let callee = function(Ret::Yes, 0, &[<u32>::get_type()], blocks);
+
+    let b0 = block!(storage_live(0), call(1, &[], local(0), Some(1)));
+    let b1 = block!(exit());
+    let main = function(Ret::No, 0, &[<u32>::get_type()], &[b0, b1]);
+
+    program(&[main, callee])
+}
+
+#[test]
+fn return_without_write() {
+    let p = call_u32_fn(&[block!(return_())]);
+    assert_ill_formed(p, "Terminator::Return: return value possibly uninitialized");
+}
+
+#[test]
+fn return_after_write() {
+    let p = call_u32_fn(&[block!(assign(local(0), const_int::<u32>(7)), return_())]);
+    assert_stop(p);
+
+    // The check is not flow-sensitive, a write in another block is fine as well.
+    let b0 = block!(goto(1));
+    let b1 = block!(assign(local(0), const_int::<u32>(7)), return_());
+    assert_stop(call_u32_fn(&[b0, b1]));
+}
+
+#[test]
+fn field_of_int() {
+    let locals = &[<u32>::get_type()];
+    let stmts = &[storage_live(0), assign(field(local(0), 0), const_int::<u32>(0))];
+    let p = small_program(locals, stmts);
+    assert_ill_formed(p, "PlaceExpr::Field: base is not an aggregate");
+}
+
+#[test]
+fn field_out_of_range() {
+    let u8_t = <u8>::get_type();
+    let locals = &[tuple_ty(&[(offset(0), u8_t), (offset(1), u8_t)], size(2), align(1))];
+    let stmts = &[storage_live(0), assign(field(local(0), 2), const_int::<u8>(0))];
+    let p = small_program(locals, stmts);
+    assert_ill_formed(p, "PlaceExpr::Field: invalid field");
+}
+
+#[test]
+fn index_of_int() {
+    let locals = &[<u32>::get_type()];
+    let stmts = &[
+        storage_live(0),
+        assign(index(local(0), const_int::<usize>(0)), const_int::<u32>(0)),
+    ];
+    let p = small_program(locals, stmts);
+    assert_ill_formed(p, "PlaceExpr::Index: base is not an array or slice");
+}
+
+#[test]
+fn deref_of_int() {
+    let locals = &[<usize>::get_type(), <u32>::get_type()];
+    let stmts = &[
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), const_int::<usize>(16)),
+        assign(local(1), load(deref(load(local(0)), <u32>::get_type()))),
+    ];
+    let p = small_program(locals, stmts);
+    assert_ill_formed(p, "PlaceExpr::Deref: operand is not a pointer");
+}
@@ -106,3 +106,75 @@ fn bit_int_not_works() {
     let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
     assert_stop(prog);
 }
+
+/// Test that `!0u8` is 255, i.e. `Not` respects the unsigned type.
+#[test]
+fn bit_int_not_unsigned() {
+    let locals = [];
+    let blocks = [
+        block!(if_(eq(int_not(const_int::<u8>(0)), const_int::<u8>(255)), 1, 2)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(prog);
+}
+
+/// Test that `Neg` works for ints, and that `-i32::MIN` wraps around like in unchecked MIR.
+#[test]
+fn int_neg_works() {
+    let locals = [];
+    let unreach_block = 3;
+    let neg = |x| int_neg(const_int::<i32>(x));
+
+    let blocks = [
+        block!(if_(eq(neg(5), const_int::<i32>(-5)), 1, unreach_block)),
+        block!(if_(eq(neg(i32::MIN), const_int::<i32>(i32::MIN)), 2, unreach_block)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(prog);
+}
+
+/// Assigns `expr` to an `i32` local.
+fn assign_i32(expr: ValueExpr) -> Program {
+    let locals = [<i32>::get_type()];
+    let b0 = block!(storage_live(0), assign(local(0), expr), exit());
+    program(&[function(Ret::No, 0, &locals, &[b0])])
+}
+
+#[test]
+fn add_mismatched_width() {
+    let prog = assign_i32(add(const_int::<i32>(1), const_int::<i64>(2)));
+    assert_ill_formed(prog, "BinOp::Int: invalid right type");
+}
+
+#[test]
+fn add_mismatched_signedness() {
+    let prog = assign_i32(add(const_int::<i32>(1), const_int::<u32>(2)));
+    assert_ill_formed(prog, "BinOp::Int: invalid right type");
+}
+
+#[test]
+fn add_bool_int() {
+    let prog = assign_i32(add(const_bool(true), const_int::<i32>(2)));
+    assert_ill_formed(prog, "BinOp::Int: invalid left type");
+
+    let prog = assign_i32(add(const_int::<i32>(2), const_bool(true)));
+    assert_ill_formed(prog, "BinOp::Int: invalid right type");
+}
+
+#[test]
+fn add_ptrs() {
+    let prog = assign_i32(add(null(), null()));
+    assert_ill_formed(prog, "BinOp::Int: invalid left type");
+}
+
+#[test]
+fn neg_bool() {
+    let prog = assign_i32(int_neg(const_bool(true)));
+    assert_ill_formed(prog, "UnOp::Int: invalid operand");
+}
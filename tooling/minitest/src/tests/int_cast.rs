@@ -0,0 +1,40 @@
+use crate::*;
+
+/// Test that narrowing and widening integer casts truncate and extend correctly.
+#[test]
+fn int_cast_works() {
+    let locals = [];
+    let unreach_block = 6;
+
+    let blocks = [
+        // `300i32 as u8` truncates.
+        block!(if_(eq(int_cast::<u8>(const_int(300i32)), const_int(44u8)), 1, unreach_block)),
+        // `-1i8 as u16` sign-extends.
+        block!(if_(eq(int_cast::<u16>(const_int(-1i8)), const_int(65535u16)), 2, unreach_block)),
+        // `200u8 as i8` reinterprets the bits.
+        block!(if_(eq(int_cast::<i8>(const_int(200u8)), const_int(-56i8)), 3, unreach_block)),
+        // `200u8 as i64` zero-extends.
+        block!(if_(eq(int_cast::<i64>(const_int(200u8)), const_int(200i64)), 4, unreach_block)),
+        // `-56i8 as i64` sign-extends.
+        block!(if_(eq(int_cast::<i64>(const_int(-56i8)), const_int(-56i64)), 5, unreach_block)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(prog);
+}
+
+/// Test that integer casts require an integer operand.
+#[test]
+fn int_cast_requires_int() {
+    let locals = [<u8>::get_type()];
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), int_cast::<u8>(const_bool(true))),
+        storage_dead(0),
+        exit(),
+    );
+    let prog = program(&[function(Ret::No, 0, &locals, &[b0])]);
+    assert_ill_formed(prog, "Cast::IntToInt: invalid operand");
+}
@@ -0,0 +1,59 @@
+use crate::*;
+
+fn int_op(op: fn(ValueExpr, ValueExpr) -> ValueExpr, l: i32, r: i32) -> Program {
+    let locals = [<i32>::get_type()];
+
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), op(const_int::<i32>(l), const_int::<i32>(r))),
+        print(load(local(0)), 1)
+    );
+    let b1 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    program(&[f])
+}
+
+#[test]
+fn div_rem_work() {
+    assert_eq!(get_stdout(int_op(div, -7, 2)).unwrap(), &["-3"]);
+    assert_eq!(get_stdout(int_op(rem, -7, 2)).unwrap(), &["-1"]);
+    assert_eq!(get_stdout(int_op(div, i32::MIN, 1)).unwrap(), &[i32::MIN.to_string()]);
+    assert_eq!(get_stdout(int_op(rem, i32::MIN, 1)).unwrap(), &["0"]);
+}
+
+#[test]
+fn div_by_zero() {
+    assert_ub(int_op(div, 5, 0), "division by zero");
+}
+
+#[test]
+fn rem_by_zero() {
+    assert_ub(int_op(rem, 5, 0), "modulus of remainder is zero");
+}
+
+#[test]
+fn div_overflow() {
+    assert_ub(int_op(div, i32::MIN, -1), "overflow in division");
+}
+
+#[test]
+fn rem_overflow() {
+    assert_ub(int_op(rem, i32::MIN, -1), "overflow in remainder");
+}
+
+#[test]
+fn unsigned_div_max() {
+    // Unsigned division can never overflow.
+    let locals = [<u32>::get_type()];
+
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), div(const_int::<u32>(u32::MAX), const_int::<u32>(1))),
+        print(load(local(0)), 1)
+    );
+    let b1 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_eq!(get_stdout(program(&[f])).unwrap(), &[u32::MAX.to_string()]);
+}
@@ -0,0 +1,71 @@
+use crate::*;
+
+/// Test that comparing signed integers takes the sign into account.
+#[test]
+fn int_rel_signed_works() {
+    let locals = [];
+    let unreach_block = 7;
+    let c = const_int::<i8>;
+
+    let blocks = [
+        block!(if_(lt(c(-1), c(0)), 1, unreach_block)),
+        block!(if_(gt(c(-1), c(0)), unreach_block, 2)),
+        block!(if_(le(c(-128), c(127)), 3, unreach_block)),
+        block!(if_(ge(c(-2), c(-1)), unreach_block, 4)),
+        block!(if_(eq(c(-42), c(-42)), 5, unreach_block)),
+        block!(if_(ne(c(-42), c(42)), 6, unreach_block)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(prog);
+}
+
+/// Test that comparing unsigned integers works for values above the signed range.
+#[test]
+fn int_rel_unsigned_works() {
+    let locals = [];
+    let unreach_block = 5;
+    let c = const_int::<u8>;
+
+    let blocks = [
+        block!(if_(gt(c(200), c(100)), 1, unreach_block)),
+        block!(if_(lt(c(200), c(100)), unreach_block, 2)),
+        block!(if_(ge(c(255), c(255)), 3, unreach_block)),
+        block!(if_(le(c(128), c(127)), unreach_block, 4)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(prog);
+}
+
+/// Test that integer comparisons require both operands to have the same type.
+#[test]
+fn int_rel_no_type_mixing() {
+    let locals = [<bool>::get_type()];
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), lt(const_int::<i8>(-1), const_int::<u8>(0))),
+        storage_dead(0),
+        exit(),
+    );
+    let prog = program(&[function(Ret::No, 0, &locals, &[b0])]);
+    assert_ill_formed(prog, "BinOp::IntRel: invalid right type");
+}
+
+/// Test that integer comparisons cannot be used on pointers.
+#[test]
+fn int_rel_requires_int() {
+    let locals = [<bool>::get_type()];
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), lt(null(), null())),
+        storage_dead(0),
+        exit(),
+    );
+    let prog = program(&[function(Ret::No, 0, &locals, &[b0])]);
+    assert_ill_formed(prog, "BinOp::IntRel: invalid left type");
+}
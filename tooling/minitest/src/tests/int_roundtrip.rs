@@ -0,0 +1,76 @@
+use crate::*;
+
+/// Runs a program printing each of the `checks` and asserts that they all print `true`.
+fn assert_all_true(checks: &[ValueExpr]) {
+    let mut blocks: Vec<BasicBlock> =
+        checks.iter().enumerate().map(|(i, c)| block!(print(*c, i as u32 + 1))).collect();
+    blocks.push(block!(exit()));
+    let f = function(Ret::No, 0, &[], &blocks);
+    let out = get_stdout(program(&[f])).unwrap();
+    assert_eq!(out, vec!["true"; checks.len()]);
+}
+
+/// For the signed type `S` and the unsigned type `U` of the same size, and each given pair of
+/// values with the same bit pattern, checks that
+/// - both values survive being encoded to bytes and decoded again,
+/// - decoding the bytes of a value at the other type gives the other value of the pair,
+///   i.e. truncation and sign extension are correct.
+fn check_int_pairs<S, U>(pairs: &[(S, U)])
+where
+    S: TypeConv + Into<Int> + Copy,
+    U: TypeConv + Into<Int> + Copy,
+{
+    let bytes_ty = array_ty(<u8>::get_type(), S::get_size().bytes());
+    let round_trip = |v: ValueExpr, ty: Type| transmute(transmute(v, bytes_ty), ty);
+    let mut checks = Vec::new();
+    for &(s, u) in pairs {
+        let s = const_int(s);
+        let u = const_int(u);
+        checks.push(eq(round_trip(s, S::get_type()), s));
+        checks.push(eq(round_trip(u, U::get_type()), u));
+        checks.push(eq(transmute(s, U::get_type()), u));
+        checks.push(eq(transmute(u, S::get_type()), s));
+    }
+    assert_all_true(&checks);
+}
+
+/// The interesting values of a signed type, paired with their bit pattern as unsigned value:
+/// the extremes, the values around zero, and the values around the sign boundary.
+macro_rules! int_pairs {
+    ($s:ty, $u:ty) => {
+        [
+            (<$s>::MIN, <$s>::MIN as $u),
+            (<$s>::MIN + 1, (<$s>::MIN + 1) as $u),
+            (-1 as $s, -1 as $s as $u),
+            (0, 0),
+            (1, 1),
+            (<$s>::MAX - 1, (<$s>::MAX - 1) as $u),
+            (<$s>::MAX, <$s>::MAX as $u),
+        ]
+    };
+}
+
+#[test]
+fn int_roundtrip_8() {
+    check_int_pairs::<i8, u8>(&int_pairs!(i8, u8));
+}
+
+#[test]
+fn int_roundtrip_16() {
+    check_int_pairs::<i16, u16>(&int_pairs!(i16, u16));
+}
+
+#[test]
+fn int_roundtrip_32() {
+    check_int_pairs::<i32, u32>(&int_pairs!(i32, u32));
+}
+
+#[test]
+fn int_roundtrip_64() {
+    check_int_pairs::<i64, u64>(&int_pairs!(i64, u64));
+}
+
+#[test]
+fn int_roundtrip_128() {
+    check_int_pairs::<i128, u128>(&int_pairs!(i128, u128));
+}
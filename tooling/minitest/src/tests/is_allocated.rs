@@ -0,0 +1,46 @@
+use crate::*;
+
+fn test_intrinsics() -> RunConfig {
+    RunConfig { test_intrinsics: true, ..RunConfig::default() }
+}
+
+/// Allocates some memory and prints whether it is allocated before and after freeing it.
+fn alloc_dealloc_program() -> Program {
+    let locals = [<*const i32>::get_type(), <bool>::get_type()];
+    let n = const_int::<usize>(4);
+    let b0 = block!(storage_live(0), storage_live(1), allocate(n, n, local(0), 1));
+    let b1 = block!(is_allocated(local(1), load(local(0)), 2));
+    let b2 = block!(print(load(local(1)), 3));
+    let b3 = block!(deallocate(load(local(0)), n, n, 4));
+    // Asking about the dangling pointer is not UB.
+    let b4 = block!(is_allocated(local(1), load(local(0)), 5));
+    let b5 = block!(print(load(local(1)), 6));
+    let b6 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5, b6]);
+    program(&[f])
+}
+
+#[test]
+fn is_allocated_after_free() {
+    let out = get_stdout_with_config(alloc_dealloc_program(), test_intrinsics()).unwrap();
+    assert_eq!(out, &["true", "false"]);
+}
+
+#[test]
+fn is_allocated_no_provenance() {
+    let locals = [<bool>::get_type()];
+    let b0 = block!(storage_live(0), is_allocated(local(0), null(), 1));
+    let b1 = block!(print(load(local(0)), 2));
+    let b2 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    let out = get_stdout_with_config(program(&[f]), test_intrinsics()).unwrap();
+    assert_eq!(out, &["false"]);
+}
+
+#[test]
+fn is_allocated_needs_flag() {
+    assert_ub(
+        alloc_dealloc_program(),
+        "`IsAllocated` intrinsic used without enabling test intrinsics",
+    );
+}
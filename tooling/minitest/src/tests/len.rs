@@ -0,0 +1,45 @@
+use crate::*;
+
+#[test]
+fn len_of_array() {
+    let arr_t = <[i32; 3]>::get_type();
+    let locals = [arr_t];
+    let blocks = [
+        block!(storage_live(0), if_(eq(len(local(0)), const_int(3usize)), 1, 2)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+    let p = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(p);
+}
+
+/// `len` does not read the array, so it can be used as a bounds check even if the array is not
+/// initialized.
+#[test]
+fn len_bounds_check() {
+    let arr_t = <[i32; 3]>::get_type();
+    let locals = [arr_t, <usize>::get_type()];
+    let blocks = [
+        block!(
+            storage_live(0),
+            storage_live(1),
+            assign(local(1), const_int(5usize)),
+            if_(lt(load(local(1)), len(local(0))), 2, 1),
+        ),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+    let p = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(p);
+}
+
+#[test]
+fn len_of_non_array() {
+    let locals = [<i32>::get_type()];
+    let blocks = [
+        block!(storage_live(0), if_(eq(len(local(0)), const_int(1usize)), 1, 1)),
+        block!(exit()),
+    ];
+    let p = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_ill_formed(p, "ValueExpr::Len: place is not an array or slice");
+}
@@ -0,0 +1,42 @@
+use crate::*;
+
+#[test]
+fn ub_location() {
+    let locals = [<i32>::get_type()];
+
+    let b0 = block!(storage_live(0), goto(1));
+    let b1 = block!(
+        assign(local(0), const_int::<i32>(1)),
+        assign(local(0), div(load(local(0)), const_int::<i32>(0))),
+        exit()
+    );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    let p = program(&[f]);
+    let (info, location) = run_program_with_location(p);
+    assert_eq!(
+        info,
+        TerminationInfo::Ub(minirust_rs::prelude::String::from_internal(
+            "division by zero".to_string()
+        ))
+    );
+    // The second statement of block 1 in function 0.
+    let expected = (FnName(Name::from_internal(0)), BbName(Name::from_internal(1)), Int::from(1));
+    assert_eq!(location, Some(expected));
+}
+
+#[test]
+fn call_location() {
+    // A call with mismatching arguments fails before the callee's frame is pushed,
+    // so the location is the call terminator of the caller.
+    let callee = function(Ret::No, 0, &[], &[block!(return_())]);
+
+    let b0 = block!(call(1, &[by_value(const_int::<u8>(0))], zst_place(), Some(1)));
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &[], &[b0, b1]);
+
+    let p = program(&[f, callee]);
+    let (_info, location) = run_program_with_location(p);
+    let expected = (FnName(Name::from_internal(0)), BbName(Name::from_internal(0)), Int::from(0));
+    assert_eq!(location, Some(expected));
+}
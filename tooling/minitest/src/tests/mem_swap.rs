@@ -0,0 +1,38 @@
+use crate::*;
+
+/// A pointer to element `i` of local 0, which is an array of `u32`.
+fn elem_ptr(i: usize) -> ValueExpr {
+    addr_of(index(local(0), const_int::<usize>(i)), <*mut u32>::get_type())
+}
+
+#[test]
+fn swap_two_u32() {
+    let locals = [<[u32; 2]>::get_type()];
+    let b0 = block!(
+        storage_live(0),
+        assign(index(local(0), const_int::<usize>(0)), const_int::<u32>(1)),
+        assign(index(local(0), const_int::<usize>(1)), const_int::<u32>(2)),
+        swap(elem_ptr(0), elem_ptr(1), const_int::<usize>(1), <u32>::get_type(), 1),
+    );
+    let b1 = block!(print(load(index(local(0), const_int::<usize>(0))), 2));
+    let b2 = block!(print(load(index(local(0), const_int::<usize>(1))), 3));
+    let b3 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+
+    assert_eq!(get_stdout(program(&[f])).unwrap(), &["2", "1"]);
+}
+
+#[test]
+fn swap_overlapping() {
+    // Swapping elements 0 and 1 with elements 1 and 2.
+    let locals = [<[u32; 4]>::get_type()];
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), transmute(const_int::<u128>(0), <[u32; 4]>::get_type())),
+        swap(elem_ptr(0), elem_ptr(1), const_int::<usize>(2), <u32>::get_type(), 1),
+    );
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+
+    assert_ub(program(&[f]), "`MemSwap` intrinsic called on overlapping ranges");
+}
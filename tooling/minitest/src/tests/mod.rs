@@ -1,35 +1,88 @@
 mod align;
+mod aliasing;
+mod array_repeat;
+mod assign_aggregate;
 mod atomic;
 mod atomic_fetch;
+mod basic_blocks;
+mod bit_manipulation;
+mod black_box;
 mod bool;
+mod box_new;
+mod branch_hint;
 mod call;
+mod check_dereferenceable_on_load;
 mod compare_exchange;
 mod concurrency;
+mod const_int;
+mod copy_nonoverlapping;
 mod data_race;
+mod deinit;
 mod dereferenceable;
 mod div_zero;
+mod dot;
+mod eager_scheduler;
+mod endianness;
 mod enum_discriminant;
 mod enum_downcast;
 mod enum_representation;
+mod equality;
 mod expose;
+mod frame_depth;
+mod freeze;
 mod heap_intrinsics;
 mod ill_formed;
 mod int;
+mod int_cast;
+mod int_div;
+mod int_rel;
+mod int_roundtrip;
 mod invalid_offset;
+mod is_allocated;
+mod len;
+mod location;
 mod locks;
 mod main;
+mod mem_swap;
+mod moves;
 mod negative_index;
 mod no_preserve_padding;
 mod no_preserve_prov;
+mod nondet;
 mod null;
+mod offset_of;
 mod packed;
+mod panic;
+mod park;
+mod pointer_width;
 mod print;
+mod print_threads;
+mod program_diff;
+mod provenance_model;
+mod ptr_guaranteed_cmp;
 mod ptr_offset;
 mod ptr_partial_overwrite;
+mod ptr_read_write;
+mod ptr_rel;
+mod raw_addr_of;
 mod return_;
+mod saturating_int_cast;
+mod simd;
+mod size_of;
 mod spawn_join;
+mod stack_overflow;
+mod stdin;
 mod switch;
+mod termination;
+mod thread_local;
 mod too_large_alloc;
+mod try_int_cast;
+mod ty_conv;
+mod type_layout;
 mod uninit_read;
 mod unreachable;
+mod value_to_string;
+mod weak_memory;
+mod wide_ptr;
+mod write_bytes_stdout;
 mod zst;
@@ -0,0 +1,59 @@
+use crate::*;
+
+fn setup() -> [Statement; 4] {
+    [storage_live(0), storage_live(1), storage_live(2), assign(local(0), const_bool(true))]
+}
+
+/// `copied_from_is_initialized`, written with `let_`.
+#[test]
+fn copied_from_is_initialized_let() {
+    let locals = [<bool>::get_type(); 3];
+    let stmts = [
+        &let_(0, const_bool(true))[..],
+        &let_(1, load(local(0))),
+        &let_(2, load(local(0))),
+    ]
+    .concat();
+    let p = small_program(&locals, &stmts);
+    assert_stop(p);
+
+    let expected = [
+        storage_live(0),
+        assign(local(0), const_bool(true)),
+        storage_live(1),
+        assign(local(1), load(local(0))),
+        storage_live(2),
+        assign(local(2), load(local(0))),
+    ];
+    assert_eq!(fmt_program(p), fmt_program(small_program(&locals, &expected)));
+}
+
+#[test]
+fn copied_from_is_initialized() {
+    let locals = [<bool>::get_type(); 3];
+    let stmts =
+        [&setup()[..], &[assign(local(1), load(local(0))), assign(local(2), load(local(0)))]]
+            .concat();
+    let p = small_program(&locals, &stmts);
+    assert_stop(p);
+}
+
+#[test]
+fn moved_from_is_uninit() {
+    let locals = [<bool>::get_type(); 3];
+    let stmts =
+        [&setup()[..], &assign_move(local(1), local(0)), &[assign(local(2), load(local(0)))]]
+            .concat();
+    let p = small_program(&locals, &stmts);
+    assert_ub(p, "load at type Bool but the data in memory violates the validity invariant");
+}
+
+#[test]
+fn moved_to_is_initialized() {
+    let locals = [<bool>::get_type(); 3];
+    let stmts =
+        [&setup()[..], &assign_move(local(1), local(0)), &[assign(local(2), load(local(1)))]]
+            .concat();
+    let p = small_program(&locals, &stmts);
+    assert_stop(p);
+}
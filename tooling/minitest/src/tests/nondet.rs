@@ -0,0 +1,51 @@
+use crate::*;
+
+fn nondet_program(min: i32, max: i32) -> Program {
+    let locals = [<i32>::get_type()];
+    let b0 = block!(storage_live(0), nondet(local(0), min, max, 1));
+    let b1 = block!(print(load(local(0)), 2));
+    // The property: the value is in the range.
+    let in_range = bool_and(
+        ge(load(local(0)), const_int::<i32>(min)),
+        le(load(local(0)), const_int::<i32>(max)),
+    );
+    let b2 = block!(if_(in_range, 3, 4));
+    let b3 = block!(exit());
+    let b4 = block!(unreachable());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4]);
+    program(&[f])
+}
+
+/// The property holds for all choices, and all choices eventually get made.
+#[test]
+fn nondet_all_choices() {
+    let p = nondet_program(0, 1);
+    let mut seen = std::collections::HashSet::new();
+    for _ in 0..64 {
+        let out = get_stdout(p).unwrap();
+        seen.insert(out[0].clone());
+    }
+    assert_eq!(seen, ["0".to_string(), "1".to_string()].into_iter().collect());
+}
+
+#[test]
+fn nondet_single_choice() {
+    let p = nondet_program(-3, -3);
+    assert_eq!(get_stdout(p).unwrap(), &["-3"]);
+}
+
+#[test]
+fn nondet_empty_range() {
+    let p = nondet_program(1, 0);
+    assert_ub(p, "invalid range for `Nondet` intrinsic: `min` is bigger than `max`");
+}
+
+#[test]
+fn nondet_unrepresentable() {
+    let locals = [<u8>::get_type()];
+    let b0 = block!(storage_live(0), nondet(local(0), 0, 256, 1));
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    let p = program(&[f]);
+    assert_ub(p, "invalid return type for `Nondet` intrinsic: cannot represent the range");
+}
@@ -0,0 +1,66 @@
+use crate::*;
+
+/// Test that `OffsetOf` computes the offset of a direct field.
+#[test]
+fn offset_of_field() {
+    // `#[repr(C)] struct { a: u8, b: u32 }`
+    let s_ty =
+        tuple_ty(&[(size(0), <u8>::get_type()), (size(4), <u32>::get_type())], size(8), align(4));
+    let locals = [<usize>::get_type()];
+
+    let blocks = [
+        block!(storage_live(0), offset_of(local(0), s_ty, &[1], 1)),
+        block!(if_(eq(load(local(0)), const_int::<usize>(4)), 2, 3)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(prog);
+}
+
+/// Test that `OffsetOf` follows nested field paths.
+#[test]
+fn offset_of_nested_field() {
+    let inner_ty =
+        tuple_ty(&[(size(0), <u8>::get_type()), (size(4), <u32>::get_type())], size(8), align(4));
+    let union_ty =
+        union_ty(&[(size(0), <u64>::get_type()), (size(0), inner_ty)], size(8), align(8));
+    let outer_ty =
+        tuple_ty(&[(size(0), <u16>::get_type()), (size(8), union_ty)], size(16), align(8));
+    let locals = [<usize>::get_type()];
+
+    let blocks = [
+        block!(storage_live(0), offset_of(local(0), outer_ty, &[1, 1, 1], 1)),
+        block!(if_(eq(load(local(0)), const_int::<usize>(12)), 2, 3)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(prog);
+}
+
+/// Test that `OffsetOf` rejects fields that do not exist.
+#[test]
+fn offset_of_invalid_field() {
+    let s_ty = tuple_ty(&[(size(0), <u8>::get_type())], size(1), align(1));
+    let locals = [<usize>::get_type()];
+
+    let blocks = [block!(storage_live(0), offset_of(local(0), s_ty, &[1], 1)), block!(exit())];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_ub(prog, "invalid field path for `OffsetOf` intrinsic: field does not exist");
+}
+
+/// Test that `OffsetOf` can only project into tuples and unions.
+#[test]
+fn offset_of_non_projectable() {
+    let locals = [<usize>::get_type()];
+
+    let blocks =
+        [block!(storage_live(0), offset_of(local(0), <u32>::get_type(), &[0], 1)), block!(exit())];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_ub(prog, "invalid field path for `OffsetOf` intrinsic: not a tuple or union");
+}
@@ -0,0 +1,34 @@
+use crate::*;
+
+#[test]
+fn call_panic_fn() {
+    let b0 = block!(panic(1));
+    let f = function(Ret::No, 0, &[], &[b0]);
+    let p = program(&[f, panic_fn()]);
+    assert_panics(p);
+}
+
+/// This is how minimize lowers an `Assert` terminator, here for an array bounds check.
+fn bounds_check(idx: usize) -> Program {
+    let locals = [<[u32; 1]>::get_type(), <u32>::get_type()];
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), array(&[const_int::<u32>(0)], <u32>::get_type())),
+        if_(lt(const_int::<usize>(idx), const_int::<usize>(1)), 1, 2)
+    );
+    let b1 = block!(assign(local(1), load(index(local(0), const_int::<usize>(idx)))), exit());
+    let b2 = block!(panic(1));
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    program(&[f, panic_fn()])
+}
+
+#[test]
+fn bounds_check_pass() {
+    assert_stop(bounds_check(0));
+}
+
+#[test]
+fn bounds_check_fail() {
+    assert_panics(bounds_check(2));
+}
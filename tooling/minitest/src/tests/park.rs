@@ -0,0 +1,71 @@
+use crate::*;
+
+fn parking_child() -> Function {
+    let locals = [<()>::get_type(), <*const ()>::get_type()];
+    let b0 = block!(park(1));
+    let b1 = block!(return_());
+    function(Ret::Yes, 1, &locals, &[b0, b1])
+}
+
+/// The child parks and the parent unparks it. This terminates no matter whether the
+/// `unpark` happens before or after the `park`.
+#[test]
+fn park_unpark_handshake() {
+    let locals = [<u32>::get_type()];
+
+    let b0 = block!(storage_live(0), spawn(fn_ptr(1), null(), local(0), 1));
+    let b1 = block!(unpark(load(local(0)), 2));
+    let b2 = block!(join(load(local(0)), 3));
+    let b3 = block!(exit());
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+
+    let p = program(&[main, parking_child()]);
+    assert_stop_always(p, 16);
+}
+
+/// Without anyone to unpark it, a parked thread is blocked forever.
+#[test]
+fn park_deadlock() {
+    let b0 = block!(park(1));
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &[], &[b0, b1]);
+
+    let p = program(&[f]);
+    assert_deadlock(p);
+}
+
+/// An earlier `unpark` makes the next `park` return immediately.
+#[test]
+fn unpark_before_park() {
+    let b0 = block!(unpark(const_int::<u32>(0), 1));
+    let b1 = block!(park(2));
+    let b2 = block!(exit());
+    let f = function(Ret::No, 0, &[], &[b0, b1, b2]);
+
+    let p = program(&[f]);
+    assert_stop(p);
+}
+
+/// Unpark tokens do not stack.
+#[test]
+fn unpark_tokens_do_not_stack() {
+    let b0 = block!(unpark(const_int::<u32>(0), 1));
+    let b1 = block!(unpark(const_int::<u32>(0), 2));
+    let b2 = block!(park(3));
+    let b3 = block!(park(4));
+    let b4 = block!(exit());
+    let f = function(Ret::No, 0, &[], &[b0, b1, b2, b3, b4]);
+
+    let p = program(&[f]);
+    assert_deadlock(p);
+}
+
+#[test]
+fn unpark_non_existing() {
+    let b0 = block!(unpark(const_int::<u32>(1), 1));
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &[], &[b0, b1]);
+
+    let p = program(&[f]);
+    assert_ub(p, "`Unpark` intrinsic: unpark non existing thread");
+}
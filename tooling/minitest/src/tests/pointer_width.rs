@@ -0,0 +1,77 @@
+use crate::*;
+
+// `<usize>::get_type()` uses the pointer size of the `DefaultTarget`,
+// so on other targets we have to spell out `usize` ourselves.
+fn usize_ty(ptr_size: u32) -> Type {
+    int_ty(Signedness::Unsigned, size(ptr_size))
+}
+
+fn print_usize_size(ptr_size: u32) -> Program {
+    let locals = [usize_ty(ptr_size)];
+
+    let b0 = block!(storage_live(0), size_of(local(0), usize_ty(ptr_size), 1));
+    let b1 = block!(print(load(local(0)), 2));
+    let b2 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    program(&[f])
+}
+
+#[test]
+fn usize_size() {
+    assert_eq!(get_stdout_for_target::<armv7>(print_usize_size(4)).unwrap(), &["4"]);
+    assert_eq!(get_stdout_for_target::<x86_64>(print_usize_size(8)).unwrap(), &["8"]);
+}
+
+#[test]
+fn usize_size_mismatch() {
+    // An 8 byte integer is not `usize` on a 32-bit target.
+    let out = get_stdout_for_target::<armv7>(print_usize_size(8));
+    assert_eq!(
+        out,
+        Err(TerminationInfo::Ub(minirust_rs::prelude::String::from_internal(
+            "invalid return type for `SizeOf` intrinsic".to_string()
+        )))
+    );
+}
+
+/// Allocates 8 bytes, stores 42 in the second half, reads it back and frees the allocation.
+/// `offset` is the offset we use to get to the second half.
+fn alloc_and_offset(offset: i64) -> Program {
+    let locals = [<*const u32>::get_type()];
+    let n = const_int::<u32>(8);
+    let a = const_int::<u32>(4);
+    let u32_at = |ptr| deref(ptr, <u32>::get_type());
+
+    let b0 = block!(storage_live(0), allocate(n, a, local(0), 1));
+    let b1 = block!(
+        assign(local(0), ptr_offset(load(local(0)), const_int::<i64>(offset), InBounds::Yes)),
+        assign(u32_at(load(local(0))), const_int::<u32>(42)),
+        print(load(u32_at(load(local(0)))), 2)
+    );
+    let b2 = block!(
+        assign(local(0), ptr_offset(load(local(0)), const_int::<i32>(-4), InBounds::Yes)),
+        deallocate(load(local(0)), n, a, 3)
+    );
+    let b3 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+    program(&[f])
+}
+
+#[test]
+fn alloc_and_offset_32bit() {
+    assert_eq!(get_stdout_for_target::<armv7>(alloc_and_offset(4)).unwrap(), &["42"]);
+}
+
+#[test]
+fn offset_exceeds_isize_32bit() {
+    // 2^31 fits into a 64-bit `isize`, but not into a 32-bit one.
+    let out = get_stdout_for_target::<armv7>(alloc_and_offset(1 << 31));
+    assert_eq!(
+        out,
+        Err(TerminationInfo::Ub(minirust_rs::prelude::String::from_internal(
+            "inbounds offset does not fit into `isize`".to_string()
+        )))
+    );
+}
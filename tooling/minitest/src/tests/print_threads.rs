@@ -0,0 +1,50 @@
+use crate::*;
+
+/// Prints all `vals` with a single `PrintStdout` call.
+fn print_all(vals: &[u32], next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::PrintStdout,
+        arguments: vals.iter().map(|v| const_int::<u32>(*v)).collect(),
+        ret: zst_place(),
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+/// Spawns a thread printing 1 and 2, then prints 3 and 4.
+/// If `pinned`, the main thread joins the other thread before printing.
+fn two_printers(pinned: bool) -> Program {
+    let locals = [<u32>::get_type()];
+    let b0 = block!(storage_live(0), spawn(fn_ptr(1), null(), local(0), 1));
+    let (b1, b2) = if pinned {
+        (block!(join(load(local(0)), 2)), block!(print_all(&[3, 4], 3)))
+    } else {
+        (block!(print_all(&[3, 4], 2)), block!(join(load(local(0)), 3)))
+    };
+    let b3 = block!(exit());
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+
+    let b0 = block!(print_all(&[1, 2], 1));
+    let b1 = block!(return_());
+    let printer = function(Ret::No, 1, &[<*const ()>::get_type()], &[b0, b1]);
+
+    program(&[main, printer])
+}
+
+/// The lines printed by one call never get interleaved with the lines of another thread.
+#[test]
+fn print_no_interleaving() {
+    let p = two_printers(false);
+    for _ in 0..20 {
+        let out = get_stdout(p).unwrap();
+        assert!(out == ["1", "2", "3", "4"] || out == ["3", "4", "1", "2"], "got {out:?}");
+    }
+}
+
+/// Under a fixed schedule, the output is deterministic.
+#[test]
+fn print_pinned_schedule() {
+    let p = two_printers(true);
+    for _ in 0..20 {
+        assert_eq!(get_stdout(p).unwrap(), &["1", "2", "3", "4"]);
+    }
+}
@@ -0,0 +1,42 @@
+use crate::*;
+
+#[test]
+fn equal_programs() {
+    let locals = [<u32>::get_type()];
+    let stmts = [storage_live(0), assign(local(0), const_int::<u32>(1))];
+    let p = small_program(&locals, &stmts);
+    assert_eq!(program_diff(p, p), "");
+}
+
+#[test]
+fn differing_statement() {
+    let locals = [<u32>::get_type(); 2];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), const_int::<u32>(1)),
+        assign(local(1), const_int::<u32>(2)),
+    ];
+    let mut changed = stmts;
+    changed[3] = assign(local(1), const_int::<u32>(3));
+
+    let diff = program_diff(small_program(&locals, &stmts), small_program(&locals, &changed));
+    assert_eq!(diff, "f0, bb0, statement 3:\n  - _1 = 2;\n  + _1 = 3;");
+}
+
+#[test]
+fn missing_statement() {
+    let locals = [<u32>::get_type()];
+    let stmts = [storage_live(0), assign(local(0), const_int::<u32>(1))];
+
+    let diff = program_diff(small_program(&locals, &stmts), small_program(&locals, &stmts[..1]));
+    assert_eq!(diff, "f0, bb0, statement 1:\n  - _0 = 1;\n  + <missing>");
+}
+
+#[test]
+fn differing_local_type() {
+    let stmts = [storage_live(0)];
+    let a = small_program(&[<u32>::get_type()], &stmts);
+    let b = small_program(&[<bool>::get_type()], &stmts);
+    assert_eq!(program_diff(a, b), "f0, local _0:\n  - u32\n  + bool");
+}
@@ -0,0 +1,46 @@
+use crate::*;
+
+fn permissive() -> RunConfig {
+    RunConfig { provenance_model: ProvenanceModel::Permissive, ..RunConfig::default() }
+}
+
+/// Stores the address of a local in local 1, turns it back into a pointer and loads from there.
+/// If `expose` is false, the address is obtained without exposing the provenance of the local.
+fn fabricated_ptr_program(expose: bool) -> Program {
+    let locals = [<i32>::get_type(), <usize>::get_type(), <*const i32>::get_type()];
+    let ptr = addr_of(local(0), <*const i32>::get_type());
+    let mut stmts = vec![
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        assign(local(0), const_int::<i32>(42)),
+    ];
+    let b0 = if expose {
+        block(&stmts, expose_provenance(local(1), ptr, 1))
+    } else {
+        // Transmuting a pointer to an integer strips its provenance.
+        stmts.push(assign(local(1), transmute(ptr, <usize>::get_type())));
+        block(&stmts, goto(1))
+    };
+    let b1 = block!(with_exposed_provenance(local(2), load(local(1)), 2));
+    let b2 = block!(print(load(deref(load(local(2)), <i32>::get_type())), 3));
+    let b3 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+    program(&[f])
+}
+
+#[test]
+fn exposed_ptr_strict() {
+    assert_eq!(get_stdout(fabricated_ptr_program(true)).unwrap(), &["42"]);
+}
+
+#[test]
+fn fabricated_ptr_strict() {
+    assert_ub(fabricated_ptr_program(false), "non-zero-sized access with invalid pointer");
+}
+
+#[test]
+fn fabricated_ptr_permissive() {
+    let out = get_stdout_with_config(fabricated_ptr_program(false), permissive());
+    assert_eq!(out.unwrap(), &["42"]);
+}
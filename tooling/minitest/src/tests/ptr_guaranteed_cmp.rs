@@ -0,0 +1,70 @@
+use crate::*;
+
+/// Prints the result of `ptr_guaranteed_cmp(l, r)` for pointers to the locals 0 and 1.
+fn guaranteed_cmp_program(l: ValueExpr, r: ValueExpr) -> Program {
+    let locals = [<u32>::get_type(), <u32>::get_type(), <u8>::get_type()];
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        ptr_guaranteed_cmp(local(2), l, r, 1)
+    );
+    let b1 = block!(print(load(local(2)), 2));
+    let b2 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    program(&[f])
+}
+
+fn addr_of_local(x: u32) -> ValueExpr {
+    addr_of(local(x), <*const u32>::get_type())
+}
+
+#[test]
+fn guaranteed_cmp_same_ptr() {
+    let p = guaranteed_cmp_program(addr_of_local(0), addr_of_local(0));
+    assert_eq!(get_stdout(p).unwrap(), &["1"]);
+}
+
+#[test]
+fn guaranteed_cmp_different_allocs() {
+    let p = guaranteed_cmp_program(addr_of_local(0), addr_of_local(1));
+    assert_eq!(get_stdout(p).unwrap(), &["0"]);
+}
+
+/// A pointer to local 0 and a pointer without provenance to the same address
+/// may compare equal or "unknown", but never unequal.
+#[test]
+fn guaranteed_cmp_maybe_unknown() {
+    let locals = [<u32>::get_type(), <*const u32>::get_type(), <u8>::get_type()];
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        assign(local(1), addr_of_local(0)),
+        // Strip the provenance, keeping the address.
+        assign(local(1), transmute(ptr_addr(load(local(1))), <*const u32>::get_type())),
+        ptr_guaranteed_cmp(local(2), addr_of_local(0), load(local(1)), 1)
+    );
+    let b1 = block!(print(load(local(2)), 2));
+    let b2 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    let p = program(&[f]);
+
+    for _ in 0..16 {
+        let out = get_stdout(p).unwrap();
+        assert!(out == ["1"] || out == ["2"], "unexpected result {out:?}");
+    }
+}
+
+#[test]
+fn guaranteed_cmp_ret_type() {
+    let locals = [<u32>::get_type(), <u32>::get_type(), <bool>::get_type()];
+    let b0 = block!(
+        storage_live(0),
+        storage_live(2),
+        ptr_guaranteed_cmp(local(2), addr_of_local(0), addr_of_local(0), 1)
+    );
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_ub(program(&[f]), "invalid return type for `PtrGuaranteedCmp` intrinsic");
+}
@@ -0,0 +1,62 @@
+use crate::*;
+
+#[test]
+fn ptr_read_write_roundtrip() {
+    let locals = [<i32>::get_type(), <*mut i32>::get_type(), <i32>::get_type()];
+    let ptr = || load(local(1));
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        assign(local(0), const_int::<i32>(1)),
+        assign(local(1), raw_addr_of(local(0))),
+        ptr_write(ptr(), const_int::<i32>(42), <i32>::get_type()),
+        ptr_read(local(2), ptr(), <i32>::get_type()),
+        print(load(local(2)), 1)
+    );
+    let b1 = block!(print(load(local(0)), 2));
+    let b2 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    let p = program(&[f]);
+    assert_eq!(get_stdout(p).unwrap(), &["42", "42"]);
+}
+
+/// Writing to uninitialized memory is fine, there is no old value that would need to be dropped.
+#[test]
+fn ptr_write_uninit() {
+    let locals = [<i32>::get_type(), <*mut i32>::get_type()];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(1), raw_addr_of(local(0))),
+        ptr_write(load(local(1)), const_int::<i32>(42), <i32>::get_type()),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_stop(p);
+}
+
+#[test]
+fn ptr_read_uninit() {
+    let locals = [<i32>::get_type(), <*mut i32>::get_type(), <i32>::get_type()];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        assign(local(1), raw_addr_of(local(0))),
+        ptr_read(local(2), load(local(1)), <i32>::get_type()),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ub(
+        p,
+        "load at type Int(IntType { signed: Signed, size: Size(4 bytes) }) but the data in memory violates the validity invariant",
+    );
+}
+
+#[test]
+fn ptr_write_misaligned() {
+    let misaligned = transmute(const_int::<usize>(1), <*mut i32>::get_type());
+    let stmts = [ptr_write(misaligned, const_int::<i32>(42), <i32>::get_type())];
+    let p = small_program(&[], &stmts);
+    assert_ub(p, "storing to a place based on a misaligned pointer");
+}
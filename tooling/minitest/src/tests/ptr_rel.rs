@@ -0,0 +1,116 @@
+use crate::*;
+
+/// Test that pointers with different provenance but the same address compare equal.
+#[test]
+fn ptr_eq_ignores_provenance() {
+    let locals = [<i32>::get_type(), <*const i32>::get_type(), <*const i32>::get_type()];
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        assign(local(1), addr_of(local(0), <*const i32>::get_type())),
+        // Round-trip through an integer to obtain a pointer without provenance.
+        assign(local(2), transmute(ptr_addr(load(local(1))), <*const i32>::get_type())),
+        if_(ptr_eq(load(local(1)), load(local(2))), 1, 2),
+    );
+    let b1 = block!(exit());
+    let b2 = block!(unreachable());
+
+    let prog = program(&[function(Ret::No, 0, &locals, &[b0, b1, b2])]);
+    assert_stop(prog);
+}
+
+/// Test that pointers to different live allocations compare inequal.
+#[test]
+fn ptr_eq_different_allocations() {
+    let locals = [<i32>::get_type(), <i32>::get_type()];
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        if_(
+            ptr_eq(
+                addr_of(local(0), <*const i32>::get_type()),
+                addr_of(local(1), <*const i32>::get_type())
+            ),
+            2,
+            1
+        ),
+    );
+    let b1 = block!(exit());
+    let b2 = block!(unreachable());
+
+    let prog = program(&[function(Ret::No, 0, &locals, &[b0, b1, b2])]);
+    assert_stop(prog);
+}
+
+/// Test that a pointer into one allocation compares equal to a pointer into another allocation
+/// when both have the same address.
+#[test]
+fn ptr_eq_same_address_different_allocations() {
+    let locals = [<i32>::get_type(), <i32>::get_type(), <*const i32>::get_type()];
+
+    let x = addr_of(local(0), <*const i32>::get_type());
+    let y = addr_of(local(1), <*const i32>::get_type());
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        // Move a pointer to `x` (keeping its provenance) to the address of `y`.
+        assign(local(2), ptr_offset(x, sub(ptr_addr(y), ptr_addr(x)), InBounds::No)),
+        if_(ptr_eq(load(local(2)), y), 1, 2),
+    );
+    let b1 = block!(exit());
+    let b2 = block!(unreachable());
+
+    let prog = program(&[function(Ret::No, 0, &locals, &[b0, b1, b2])]);
+    assert_stop(prog);
+}
+
+/// Test that ordering pointers within one allocation matches the order of their offsets.
+#[test]
+fn ptr_cmp_same_allocation() {
+    let locals = [<[i32; 4]>::get_type(), <*const i32>::get_type(), <*const i32>::get_type()];
+    let unreach_block = 6;
+
+    let blocks = [
+        block!(
+            storage_live(0),
+            storage_live(1),
+            storage_live(2),
+            assign(
+                local(1),
+                addr_of(index(local(0), const_int::<usize>(0)), <*const i32>::get_type())
+            ),
+            assign(
+                local(2),
+                addr_of(index(local(0), const_int::<usize>(2)), <*const i32>::get_type())
+            ),
+            goto(1),
+        ),
+        block!(if_(ptr_cmp(IntRel::Lt, load(local(1)), load(local(2))), 2, unreach_block)),
+        block!(if_(ptr_cmp(IntRel::Gt, load(local(1)), load(local(2))), unreach_block, 3)),
+        block!(if_(ptr_cmp(IntRel::Ge, load(local(2)), load(local(2))), 4, unreach_block)),
+        block!(if_(ptr_cmp(IntRel::Ne, load(local(1)), load(local(2))), 5, unreach_block)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(prog);
+}
+
+/// Test that pointer relations require pointer operands.
+#[test]
+fn ptr_rel_requires_ptr() {
+    let locals = [<bool>::get_type()];
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), ptr_eq(null(), const_int::<usize>(0))),
+        storage_dead(0),
+        exit(),
+    );
+    let prog = program(&[function(Ret::No, 0, &locals, &[b0])]);
+    assert_ill_formed(prog, "BinOp::PtrRel: invalid right type");
+}
@@ -0,0 +1,67 @@
+use crate::*;
+
+/// Raw pointers to uninitialized memory are fine.
+#[test]
+fn raw_addr_of_uninit() {
+    let locals = [<i32>::get_type(), <*mut i32>::get_type()];
+    let stmts = [storage_live(0), storage_live(1), assign(local(1), raw_addr_of(local(0)))];
+    let p = small_program(&locals, &stmts);
+    assert_stop(p);
+}
+
+/// References do not require the pointee to be initialized either, only to be dereferenceable.
+#[test]
+fn ref_of_uninit() {
+    let locals = [<i32>::get_type(), <&mut i32>::get_type()];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(1), addr_of(local(0), <&mut i32>::get_type())),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_stop(p);
+}
+
+/// Shared references, however, require the pointee to hold a valid value.
+#[test]
+fn shared_ref_of_uninit() {
+    let locals = [<i32>::get_type(), <&i32>::get_type()];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(1), addr_of(local(0), <&i32>::get_type())),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ub(
+        p,
+        "creating a shared reference to a place that does not hold a valid value of its type",
+    );
+}
+
+/// Raw pointers to misaligned packed fields are fine, while references to them are not
+/// (see `packed_is_not_aligned`).
+#[test]
+fn raw_addr_of_packed_field() {
+    // `#[repr(packed)] struct { a: u8, b: i32 }`
+    let packed_ty =
+        tuple_ty(&[(size(0), <u8>::get_type()), (size(1), <i32>::get_type())], size(5), align(1));
+    let locals = [packed_ty, <*const i32>::get_type()];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(1), raw_addr_of(field(local(0), 1))),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_stop(p);
+}
+
+/// Raw pointers to a place that is out-of-bounds are fine, too.
+#[test]
+fn raw_addr_of_dangling() {
+    let locals = [<*const i32>::get_type()];
+    let dangling = transmute(const_int::<usize>(16), <*const i32>::get_type());
+    let stmts =
+        [storage_live(0), assign(local(0), raw_addr_of(deref(dangling, <i32>::get_type())))];
+    let p = small_program(&locals, &stmts);
+    assert_stop(p);
+}
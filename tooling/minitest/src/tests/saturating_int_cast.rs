@@ -0,0 +1,28 @@
+use crate::*;
+
+fn saturating_cast<T: TypeConv>(val: ValueExpr) -> Vec<String> {
+    let locals = [T::get_type()];
+    let b0 = block!(storage_live(0), saturating_int_cast(local(0), val, 1));
+    let b1 = block!(print(load(local(0)), 2));
+    let b2 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    get_stdout(program(&[f])).unwrap()
+}
+
+#[test]
+fn saturating_int_cast_max() {
+    assert_eq!(saturating_cast::<u8>(const_int::<i32>(300)), &["255"]);
+    assert_eq!(saturating_cast::<i8>(const_int::<i32>(200)), &["127"]);
+}
+
+#[test]
+fn saturating_int_cast_min() {
+    assert_eq!(saturating_cast::<u8>(const_int::<i32>(-5)), &["0"]);
+    assert_eq!(saturating_cast::<i8>(const_int::<i32>(-200)), &["-128"]);
+}
+
+#[test]
+fn saturating_int_cast_in_range() {
+    assert_eq!(saturating_cast::<u8>(const_int::<i32>(200)), &["200"]);
+    assert_eq!(saturating_cast::<i64>(const_int::<i8>(-1)), &["-1"]);
+}
@@ -0,0 +1,244 @@
+use crate::*;
+
+fn i32x4(lanes: [i32; 4]) -> ValueExpr {
+    let lanes = lanes.map(const_int::<i32>);
+    vector(&lanes, <i32>::get_type())
+}
+
+fn i32x2(lanes: [i32; 2]) -> ValueExpr {
+    let lanes = lanes.map(const_int::<i32>);
+    vector(&lanes, <i32>::get_type())
+}
+
+/// Test that `SimdBinOp` adds two vectors lane-wise.
+#[test]
+fn simd_add_works() {
+    let vec_ty = vector_ty(<i32>::get_type(), 4);
+    let locals = [vec_ty, <[i32; 4]>::get_type()];
+    let unreach_block = 6;
+    let lane = |i: usize| load(index(local(1), const_int::<usize>(i)));
+
+    let blocks = [
+        block!(
+            storage_live(0),
+            storage_live(1),
+            simd_bin_op(
+                IntBinOp::Add,
+                local(0),
+                i32x4([1, 2, 3, i32::MAX]),
+                i32x4([10, 20, -30, 1]),
+                1
+            )
+        ),
+        block!(
+            assign(local(1), transmute(load(local(0)), <[i32; 4]>::get_type())),
+            if_(eq(lane(0), const_int::<i32>(11)), 2, unreach_block)
+        ),
+        block!(if_(eq(lane(1), const_int::<i32>(22)), 3, unreach_block)),
+        block!(if_(eq(lane(2), const_int::<i32>(-27)), 4, unreach_block)),
+        // Overflow wraps around in each lane.
+        block!(if_(eq(lane(3), const_int::<i32>(i32::MIN)), 5, unreach_block)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(prog);
+}
+
+/// Test that `SimdIntRel` produces a mask of -1 and 0 lanes.
+#[test]
+fn simd_rel_works() {
+    let locals = [vector_ty(<i32>::get_type(), 4), <[i32; 4]>::get_type()];
+    let unreach_block = 6;
+    let lane = |i: usize| load(index(local(1), const_int::<usize>(i)));
+
+    let blocks = [
+        block!(
+            storage_live(0),
+            storage_live(1),
+            simd_rel(IntRel::Lt, local(0), i32x4([1, 5, -3, 0]), i32x4([2, 5, -4, 1]), 1)
+        ),
+        block!(
+            assign(local(1), transmute(load(local(0)), <[i32; 4]>::get_type())),
+            if_(eq(lane(0), const_int::<i32>(-1)), 2, unreach_block)
+        ),
+        block!(if_(eq(lane(1), const_int::<i32>(0)), 3, unreach_block)),
+        block!(if_(eq(lane(2), const_int::<i32>(0)), 4, unreach_block)),
+        block!(if_(eq(lane(3), const_int::<i32>(-1)), 5, unreach_block)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(prog);
+}
+
+/// Test that a vector is aligned to its total size.
+#[test]
+fn simd_vector_aligned_to_size() {
+    let locals = [vector_ty(<i32>::get_type(), 4)];
+    let addr = ptr_addr(addr_of(local(0), <*const ()>::get_type()));
+
+    let blocks = [
+        block!(
+            storage_live(0),
+            if_(eq(bit_and(addr, const_int::<usize>(15)), const_int::<usize>(0)), 1, 2)
+        ),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(prog);
+}
+
+/// Test that the lane count of a vector must be a power of two.
+#[test]
+fn simd_lane_count_power_of_two() {
+    let locals = [vector_ty(<i32>::get_type(), 3)];
+    let b0 = block!(storage_live(0), exit());
+    let prog = program(&[function(Ret::No, 0, &locals, &[b0])]);
+    assert_ill_formed(prog, "Type::Vector: lane count is not a power of two");
+}
+
+/// Test that `SimdBinOp` requires both vectors to have the same type.
+#[test]
+fn simd_bin_op_type_mismatch() {
+    let locals = [vector_ty(<i32>::get_type(), 4)];
+    let other = vector(&[const_int::<i64>(0), const_int::<i64>(0)], <i64>::get_type());
+    let b0 = block!(
+        storage_live(0),
+        simd_bin_op(IntBinOp::Add, local(0), i32x4([0; 4]), other, 1)
+    );
+    let b1 = block!(exit());
+    let prog = program(&[function(Ret::No, 0, &locals, &[b0, b1])]);
+    assert_ub(
+        prog,
+        "invalid second argument to `SimdBinOp` intrinsic: not same type as first argument",
+    );
+}
+
+/// Test that `SimdExtract` reads the given lane.
+#[test]
+fn simd_extract_works() {
+    let locals = [<i32>::get_type()];
+
+    let blocks = [
+        block!(
+            storage_live(0),
+            simd_extract(local(0), i32x4([10, 20, 30, 40]), const_int::<u32>(2), 1)
+        ),
+        block!(if_(eq(load(local(0)), const_int::<i32>(30)), 2, 3)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(prog);
+}
+
+/// Test that `SimdExtract` with an out-of-bounds lane index is UB.
+#[test]
+fn simd_extract_out_of_bounds() {
+    let locals = [<i32>::get_type()];
+
+    let blocks = [
+        block!(storage_live(0), simd_extract(local(0), i32x4([0; 4]), const_int::<u32>(4), 1)),
+        block!(exit()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_ub(prog, "`SimdExtract` intrinsic: lane index out of bounds");
+}
+
+/// Test that `SimdInsert` replaces only the given lane.
+#[test]
+fn simd_insert_works() {
+    let locals = [vector_ty(<i32>::get_type(), 4), <[i32; 4]>::get_type()];
+    let unreach_block = 4;
+    let lane = |i: usize| load(index(local(1), const_int::<usize>(i)));
+
+    let blocks = [
+        block!(
+            storage_live(0),
+            storage_live(1),
+            simd_insert(
+                local(0),
+                i32x4([10, 20, 30, 40]),
+                const_int::<u32>(0),
+                const_int::<i32>(-1),
+                1
+            )
+        ),
+        block!(
+            assign(local(1), transmute(load(local(0)), <[i32; 4]>::get_type())),
+            if_(eq(lane(0), const_int::<i32>(-1)), 2, unreach_block)
+        ),
+        block!(if_(eq(lane(1), const_int::<i32>(20)), 3, unreach_block)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(prog);
+}
+
+/// Test that `SimdInsert` with an out-of-bounds lane index is UB.
+#[test]
+fn simd_insert_out_of_bounds() {
+    let locals = [vector_ty(<i32>::get_type(), 4)];
+
+    let blocks = [
+        block!(
+            storage_live(0),
+            simd_insert(local(0), i32x4([0; 4]), const_int::<u32>(7), const_int::<i32>(0), 1)
+        ),
+        block!(exit()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_ub(prog, "`SimdInsert` intrinsic: lane index out of bounds");
+}
+
+/// Test that `SimdShuffle` can combine two 2-lane vectors into a reversed 4-lane vector.
+#[test]
+fn simd_shuffle_works() {
+    let locals = [vector_ty(<i32>::get_type(), 4), <[i32; 4]>::get_type()];
+    let unreach_block = 6;
+    let lane = |i: usize| load(index(local(1), const_int::<usize>(i)));
+
+    let blocks = [
+        block!(
+            storage_live(0),
+            storage_live(1),
+            simd_shuffle(local(0), i32x2([0, 1]), i32x2([2, 3]), &[3, 2, 1, 0], 1)
+        ),
+        block!(
+            assign(local(1), transmute(load(local(0)), <[i32; 4]>::get_type())),
+            if_(eq(lane(0), const_int::<i32>(3)), 2, unreach_block)
+        ),
+        block!(if_(eq(lane(1), const_int::<i32>(2)), 3, unreach_block)),
+        block!(if_(eq(lane(2), const_int::<i32>(1)), 4, unreach_block)),
+        block!(if_(eq(lane(3), const_int::<i32>(0)), 5, unreach_block)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(prog);
+}
+
+/// Test that `SimdShuffle` indices must be in range of the combined input lanes.
+#[test]
+fn simd_shuffle_out_of_bounds() {
+    let locals = [vector_ty(<i32>::get_type(), 2)];
+
+    let blocks = [
+        block!(storage_live(0), simd_shuffle(local(0), i32x2([0, 1]), i32x2([2, 3]), &[0, 4], 1)),
+        block!(exit()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_ub(prog, "`SimdShuffle` intrinsic: lane index out of bounds");
+}
@@ -0,0 +1,60 @@
+use crate::*;
+
+/// Test that `SizeOf` and `AlignOf` report the type layout, including trailing padding.
+#[test]
+fn size_and_align_of() {
+    // `#[repr(C)] struct { a: u32, b: u8 }`
+    let s_ty =
+        tuple_ty(&[(size(0), <u32>::get_type()), (size(4), <u8>::get_type())], size(8), align(4));
+    let locals = [<usize>::get_type()];
+
+    let blocks = [
+        block!(storage_live(0), size_of(local(0), s_ty, 1)),
+        block!(if_(eq(load(local(0)), const_int::<usize>(8)), 2, 5)),
+        block!(align_of(local(0), s_ty, 3)),
+        block!(if_(eq(load(local(0)), const_int::<usize>(4)), 4, 5)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(prog);
+}
+
+#[test]
+fn size_of_zst() {
+    let locals = [<usize>::get_type()];
+
+    let blocks = [
+        block!(storage_live(0), size_of(local(0), <()>::get_type(), 1)),
+        block!(if_(eq(load(local(0)), const_int::<usize>(0)), 2, 3)),
+        block!(exit()),
+        block!(unreachable()),
+    ];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_stop(prog);
+}
+
+#[test]
+fn size_of_wrong_return_type() {
+    let locals = [<u8>::get_type()];
+
+    let blocks = [block!(storage_live(0), size_of(local(0), <u32>::get_type(), 1)), block!(exit())];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_ub(prog, "invalid return type for `SizeOf` intrinsic");
+}
+
+/// The type argument must be well-formed, even though no local has that type.
+#[test]
+fn size_of_ill_formed_type() {
+    let locals = [<usize>::get_type()];
+    // This array is larger than `isize::MAX` bytes.
+    let huge = array_ty(<u64>::get_type(), 1usize << 62);
+
+    let blocks = [block!(storage_live(0), size_of(local(0), huge, 1)), block!(exit())];
+
+    let prog = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_ill_formed(prog, "Type: size not valid");
+}
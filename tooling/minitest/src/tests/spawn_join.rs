@@ -243,3 +243,56 @@ fn join_no_thread() {
 
     assert_ub(p, "`Join` intrinsic: join non existing thread");
 }
+
+/// The main thread has id 0.
+#[test]
+fn current_thread_id_main() {
+    let locals = [<u32>::get_type()];
+
+    let b0 = block!(storage_live(0), current_thread_id(local(0), 1));
+    let b1 = block!(if_(eq(load(local(0)), const_int::<u32>(0)), 2, 3));
+    let b2 = block!(exit());
+    let b3 = block!(unreachable());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+
+    let p = program(&[f]);
+    assert_stop(p);
+}
+
+/// The id returned by `spawn` is the id the spawned thread observes.
+#[test]
+fn current_thread_id_matches_spawn() {
+    let u32_ptr = <*mut u32>::get_type();
+    let locals = [<u32>::get_type(), <u32>::get_type()];
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        spawn(fn_ptr(1), addr_of(local(1), u32_ptr), local(0), 1),
+    );
+    let b1 = block!(join(load(local(0)), 2));
+    let b2 = block!(if_(eq(load(local(0)), load(local(1))), 3, 4));
+    let b3 = block!(exit());
+    let b4 = block!(unreachable());
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4]);
+
+    let locals = [<()>::get_type(), u32_ptr];
+    let b0 = block!(current_thread_id(deref(load(local(1)), <u32>::get_type()), 1));
+    let b1 = block!(return_());
+    let child = function(Ret::Yes, 1, &locals, &[b0, b1]);
+
+    let p = program(&[main, child]);
+    assert_stop(p);
+}
+
+#[test]
+fn current_thread_id_wrongreturn() {
+    let locals = [<()>::get_type()];
+
+    let b0 = block!(storage_live(0), current_thread_id(local(0), 1));
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+
+    let p = program(&[f]);
+    assert_ub(p, "invalid return type for `CurrentThreadId` intrinsic");
+}
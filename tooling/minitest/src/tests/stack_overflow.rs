@@ -0,0 +1,89 @@
+use crate::*;
+
+/// Calls itself with `n - 1` until `n` is 0.
+fn countdown() -> Function {
+    let locals = [<()>::get_type(), <u32>::get_type(), <()>::get_type()];
+    let b0 = block!(if_(eq(load(local(1)), const_int(0u32)), 3, 1));
+    let b1 = block!(
+        storage_live(2),
+        call(1, &[by_value(sub(load(local(1)), const_int(1u32)))], local(2), Some(2))
+    );
+    let b2 = block!(storage_dead(2), return_());
+    let b3 = block!(return_());
+    function(Ret::Yes, 1, &locals, &[b0, b1, b2, b3])
+}
+
+/// Calls itself forever.
+fn infinite() -> Function {
+    let locals = [<()>::get_type(), <()>::get_type()];
+    let b0 = block!(storage_live(1), call(2, &[], local(1), Some(1)));
+    let b1 = block!(return_());
+    function(Ret::Yes, 0, &locals, &[b0, b1])
+}
+
+/// Has a 4 KiB array as local, but does not call anything.
+fn huge_frame() -> Function {
+    let locals = [<()>::get_type(), <[u8; 4096]>::get_type()];
+    let b0 = block!(storage_live(1), storage_dead(1), return_());
+    function(Ret::Yes, 0, &locals, &[b0])
+}
+
+fn call_program(f: u32, args: &[ArgumentExpr]) -> Program {
+    let locals = [<()>::get_type()];
+    let b0 = block!(storage_live(0), call(f, args, local(0), Some(1)));
+    let b1 = block!(exit());
+    let main = function(Ret::No, 0, &locals, &[b0, b1]);
+    program(&[main, countdown(), infinite(), huge_frame()])
+}
+
+#[test]
+fn unbounded_recursion() {
+    let p = call_program(2, &[]);
+    assert_stack_overflow(p, 100);
+}
+
+#[test]
+fn bounded_recursion() {
+    // `main` plus 51 frames of `countdown` stay below the limit.
+    let p = call_program(1, &[by_value(const_int(50u32))]);
+    let config = RunConfig { stack_limit: Some(100), ..RunConfig::default() };
+    assert_eq!(run_program_with_config(p, config), TerminationInfo::MachineStop(Int::ZERO));
+}
+
+#[test]
+fn recursion_too_deep() {
+    let p = call_program(1, &[by_value(const_int(200u32))]);
+    assert_stack_overflow(p, 100);
+}
+
+fn stack_bytes(limit: usize) -> RunConfig {
+    RunConfig { stack_bytes: Some(limit), ..RunConfig::default() }
+}
+
+#[test]
+fn deep_shallow_frames_fit() {
+    // 51 frames of `countdown` with 4 bytes of locals each.
+    let p = call_program(1, &[by_value(const_int(50u32))]);
+    let info = run_program_with_config(p, stack_bytes(2048));
+    assert_eq!(info, TerminationInfo::MachineStop(Int::ZERO));
+}
+
+#[test]
+fn deep_shallow_frames_overflow() {
+    let p = call_program(1, &[by_value(const_int(1000u32))]);
+    assert_eq!(run_program_with_config(p, stack_bytes(2048)), TerminationInfo::StackOverflow);
+}
+
+#[test]
+fn shallow_huge_frame_overflows() {
+    // A single frame exceeds the budget, even though the call depth is only 2.
+    let p = call_program(3, &[]);
+    assert_eq!(run_program_with_config(p, stack_bytes(2048)), TerminationInfo::StackOverflow);
+}
+
+#[test]
+fn shallow_huge_frame_fits_larger_stack() {
+    let p = call_program(3, &[]);
+    let info = run_program_with_config(p, stack_bytes(8192));
+    assert_eq!(info, TerminationInfo::MachineStop(Int::ZERO));
+}
@@ -0,0 +1,44 @@
+use crate::*;
+
+/// Reads input bytes until the end of the input and echoes them, followed by the EOF marker.
+#[test]
+fn read_stdin_echo() {
+    let locals = [<i32>::get_type()];
+
+    let b0 = block!(storage_live(0), read_stdin(local(0), 1));
+    let b1 = block!(print(load(local(0)), 2));
+    let b2 = block!(read_stdin(local(0), 3));
+    let b3 = block!(print(load(local(0)), 4));
+    let b4 = block!(read_stdin(local(0), 5));
+    let b5 = block!(print(load(local(0)), 6));
+    let b6 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5, b6]);
+    let p = program(&[f]);
+    assert_eq!(get_stdout_with_stdin(p, b"AB").unwrap(), &["65", "66", "-1"]);
+}
+
+#[test]
+fn read_stdin_empty() {
+    let locals = [<i32>::get_type()];
+
+    let b0 = block!(storage_live(0), read_stdin(local(0), 1));
+    let b1 = block!(print(load(local(0)), 2));
+    let b2 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    let p = program(&[f]);
+    assert_eq!(get_stdout(p).unwrap(), &["-1"]);
+}
+
+#[test]
+fn read_stdin_wrong_return_type() {
+    let locals = [<u8>::get_type()];
+
+    let b0 = block!(storage_live(0), read_stdin(local(0), 1));
+    let b1 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    let p = program(&[f]);
+    assert_ub(p, "invalid return type for `ReadStdin` intrinsic");
+}
@@ -109,3 +109,39 @@ fn switch_enum_works() {
     let program = program(&[function(Ret::No, 0, &locals, &blocks)]);
     assert_stop(program);
 }
+
+/// tests that a switch on a boolean is canonicalized to the form generated by `if_`.
+#[test]
+fn bool_switch_canonicalizes_to_if() {
+    let cond = bool_to_int::<u8>(load(local(0)));
+    let switch = switch_int(cond, &[(0u8, 2), (1u8, 1)], 3);
+    assert!(is_canonical_switch(switch));
+    assert_eq!(switch, if_(load(local(0)), 1, 2));
+
+    // A single case for `0` also gets turned around.
+    let switch = switch_int(cond, &[(0u8, 2)], 1);
+    assert_eq!(switch, if_(load(local(0)), 1, 2));
+
+    // Switches on integers are left alone.
+    let int_switch = switch_int(const_int(1u8), &[(0u8, 2), (1u8, 1)], 3);
+    assert!(is_canonical_switch(int_switch));
+    assert_eq!(canonicalize_switch(int_switch), int_switch);
+}
+
+/// tests that a non-canonical boolean switch is detected,
+/// and that it behaves the same once canonicalized.
+#[test]
+fn bool_switch_not_canonical() {
+    let non_canonical = Terminator::Switch {
+        value: bool_to_int::<u8>(const_bool(false)),
+        cases: [(Int::ZERO, BbName(Name::from_internal(1)))].into_iter().collect(),
+        fallback: BbName(Name::from_internal(2)),
+    };
+    assert!(!is_canonical_switch(non_canonical));
+
+    for terminator in [non_canonical, canonicalize_switch(non_canonical)] {
+        let blocks = [block(&[], terminator), block!(exit()), block!(unreachable())];
+        let program = program(&[function(Ret::No, 0, &[], &blocks)]);
+        assert_stop(program);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::*;
+
+#[test]
+fn machine_stop() {
+    let p = small_program(&[], &[]);
+    assert_exit_code(p, 0);
+}
+
+#[test]
+fn exit_code() {
+    let f = function(Ret::No, 0, &[], &[block!(exit_with_code(const_int::<i32>(42)))]);
+    let p = program(&[f]);
+    assert_exit_code(p, 42);
+}
+
+#[test]
+fn exit_code_not_int() {
+    let f = function(Ret::No, 0, &[], &[block!(exit_with_code(const_bool(true)))]);
+    let p = program(&[f]);
+    assert_ub(p, "invalid first argument to `Exit` intrinsic: not an integer");
+}
+
+#[test]
+fn abort_stops() {
+    let f = function(Ret::No, 0, &[], &[block!(abort())]);
+    let p = program(&[f]);
+    assert_abort(p);
+}
+
+/// Unlike `exit`, `abort` does not check for memory leaks.
+#[test]
+fn abort_ignores_leaks() {
+    let locals = [<*const u8>::get_type()];
+    let size = const_int::<usize>(1);
+    let align = const_int::<usize>(1);
+    let b0 = block!(storage_live(0), allocate(size, align, local(0), 1));
+    let b1 = block!(abort());
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    let p = program(&[f]);
+    assert_abort(p);
+}
+
+#[test]
+fn ub() {
+    let f = function(Ret::No, 0, &[], &[block!(unreachable())]);
+    let p = program(&[f]);
+    assert_ub(p, "reached unreachable code");
+}
+
+#[test]
+fn ill_formed() {
+    let p = program(&[]);
+    assert_ill_formed(p, "Program: start function does not exist");
+}
+
+#[test]
+fn deadlock() {
+    let f = function(Ret::No, 0, &[], &[block!(park(1)), block!(exit())]);
+    let p = program(&[f]);
+    assert_deadlock(p);
+}
+
+#[test]
+fn step_limit() {
+    let f = function(Ret::No, 0, &[], &[block!(goto(0))]);
+    let p = program(&[f]);
+    assert_step_limit(p, 100);
+}
+
+/// A program that finishes within the limit is not affected by it.
+#[test]
+fn step_limit_not_reached() {
+    let p = small_program(&[], &[]);
+    assert_eq!(run_program_with_step_limit(p, 100), TerminationInfo::MachineStop(Int::ZERO));
+}
+
+/// After some straight-line code, the program loops through blocks 2 and 3 forever.
+#[test]
+fn step_limit_names_loop() {
+    let bbs = basic_blocks! {
+        b0 => block!(goto(b1)),
+        b1 => block!(goto(head)),
+        head => block!(goto(tail)),
+        tail => block!(goto(head)),
+    };
+    let p = program(&[function(Ret::No, 0, &[], &bbs)]);
+    let (info, hottest) = run_program_with_step_limit_report(p, 100);
+    assert_eq!(info, TerminationInfo::StepLimit);
+    let hottest = hottest.unwrap();
+    assert!(hottest.fn_name == FnName(Name::from_internal(0)));
+    assert!(hottest.block == BbName(Name::from_internal(2)));
+    assert!(hottest.entries > 1);
+}
+
+/// A program that makes linear progress enters no block twice.
+#[test]
+fn step_limit_linear_progress() {
+    let mut bbs: Vec<BasicBlock> = (0..9).map(|i| block!(goto(i + 1))).collect();
+    bbs.push(block!(exit()));
+    let p = program(&[function(Ret::No, 0, &[], &bbs)]);
+    let (info, hottest) = run_program_with_step_limit_report(p, 5);
+    assert_eq!(info, TerminationInfo::StepLimit);
+    assert_eq!(hottest.unwrap().entries, 1);
+}
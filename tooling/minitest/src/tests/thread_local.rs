@@ -0,0 +1,55 @@
+use crate::*;
+
+fn tls() -> PlaceExpr {
+    deref(load(local(1)), <u32>::get_type())
+}
+
+/// Each thread has its own instance of a thread-local global, initialized from the global.
+#[test]
+fn thread_local_per_thread() {
+    // Prints the initial value of its instance, then overwrites it.
+    fn thread() -> Function {
+        let locals = [<*const ()>::get_type(), <*const u32>::get_type()];
+        let b0 = block!(storage_live(1), thread_local_addr(local(1), 0, 1));
+        let b1 = block!(print(load(tls()), 2));
+        let b2 = block!(assign(tls(), const_int::<u32>(7)), return_());
+        function(Ret::No, 1, &locals, &[b0, b1, b2])
+    }
+
+    let locals = [<u32>::get_type(), <*const u32>::get_type()];
+    let b0 = block!(storage_live(0), storage_live(1), thread_local_addr(local(1), 0, 1));
+    let b1 = block!(assign(tls(), const_int::<u32>(5)), spawn(fn_ptr(1), null(), local(0), 2));
+    let b2 = block!(join(load(local(0)), 3));
+    // The other thread did not change our instance.
+    let b3 = block!(print(load(tls()), 4));
+    let b4 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4]);
+
+    let p = program_with_globals(&[f, thread()], &[global_int::<u32>()]);
+    assert_eq!(get_stdout(p).unwrap(), &["0", "5"]);
+}
+
+/// A thread accessing a thread-local global twice gets the same instance.
+#[test]
+fn thread_local_same_instance() {
+    let locals = [<*const u32>::get_type(); 3];
+    let b0 = block!(storage_live(1), storage_live(2), thread_local_addr(local(1), 0, 1));
+    let b1 = block!(assign(tls(), const_int::<u32>(3)), thread_local_addr(local(2), 0, 2));
+    let b2 = block!(print(load(deref(load(local(2)), <u32>::get_type())), 3));
+    let b3 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+
+    let p = program_with_globals(&[f], &[global_int::<u32>()]);
+    assert_eq!(get_stdout(p).unwrap(), &["3"]);
+}
+
+#[test]
+fn thread_local_unknown_global() {
+    let locals = [<*const u32>::get_type(); 2];
+    let b0 = block!(storage_live(1), thread_local_addr(local(1), 0, 1));
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+
+    let p = program(&[f]);
+    assert_ill_formed(p, "IntrinsicOp::ThreadLocalAddr: unknown global");
+}
@@ -0,0 +1,39 @@
+use crate::*;
+
+/// Converts `val` to `u8` and prints the resulting value and whether it fit.
+fn try_into_u8(val: ValueExpr) -> Program {
+    let pair_ty =
+        tuple_ty(&[(size(0), <u8>::get_type()), (size(1), <bool>::get_type())], size(2), align(1));
+    let locals = [pair_ty];
+    let b0 = block!(storage_live(0), try_int_cast(local(0), val, 1));
+    let b1 = block!(print(load(field(local(0), 0)), 2));
+    let b2 = block!(print(load(field(local(0), 1)), 3));
+    let b3 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+    program(&[f])
+}
+
+#[test]
+fn try_int_cast_fits() {
+    assert_eq!(get_stdout(try_into_u8(const_int::<i32>(200))).unwrap(), &["200", "true"]);
+}
+
+#[test]
+fn try_int_cast_too_big() {
+    assert_eq!(get_stdout(try_into_u8(const_int::<i32>(300))).unwrap(), &["44", "false"]);
+}
+
+#[test]
+fn try_int_cast_negative() {
+    assert_eq!(get_stdout(try_into_u8(const_int::<i32>(-1))).unwrap(), &["255", "false"]);
+}
+
+#[test]
+fn try_int_cast_wrong_ret_ty() {
+    let locals = [<u8>::get_type()];
+    let b0 = block!(storage_live(0), try_int_cast(local(0), const_int::<i32>(1), 1));
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    let p = program(&[f]);
+    assert_ub(p, "invalid return type for `TryIntCast` intrinsic: not a tuple");
+}
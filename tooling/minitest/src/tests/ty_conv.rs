@@ -0,0 +1,95 @@
+use crate::*;
+
+#[test]
+fn ptr_types() {
+    let i32_layout = <i32>::get_layout();
+    assert_eq!(
+        <&i32>::get_type(),
+        Type::Ptr(PtrType::Ref { mutbl: Mutability::Immutable, pointee: i32_layout })
+    );
+    assert_eq!(
+        <&mut i32>::get_type(),
+        Type::Ptr(PtrType::Ref { mutbl: Mutability::Mutable, pointee: i32_layout })
+    );
+    assert_eq!(
+        <*const u8>::get_type(),
+        Type::Ptr(PtrType::Raw { meta_kind: PointerMetaKind::None })
+    );
+    assert_eq!(<*mut u8>::get_type(), Type::Ptr(PtrType::Raw { meta_kind: PointerMetaKind::None }));
+}
+
+/// Reads an `i32` local through a `&i32`, and writes a `u8` local through a `*mut u8`.
+#[test]
+fn ptr_typed_locals() {
+    let locals =
+        [<i32>::get_type(), <&i32>::get_type(), <u8>::get_type(), <*mut u8>::get_type()];
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        storage_live(3),
+        assign(local(0), const_int::<i32>(42)),
+        assign(local(1), addr_of(local(0), <&i32>::get_type())),
+        assign(local(3), addr_of(local(2), <*mut u8>::get_type())),
+        assign(deref(load(local(3)), <u8>::get_type()), const_int::<u8>(7)),
+        print(load(deref(load(local(1)), <i32>::get_type())), 1),
+    );
+    let b1 = block!(print(load(local(2)), 2));
+    let b2 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+
+    assert_eq!(get_stdout(program(&[f])).unwrap(), &["42", "7"]);
+}
+
+/// The offsets of the fields of `ty`, which must be a tuple.
+fn field_offsets(ty: Type) -> Vec<usize> {
+    let Type::Tuple { fields, .. } = ty else { panic!("not a tuple: {ty:?}") };
+    fields.iter().map(|(off, _)| off.bytes().try_to_usize().unwrap()).collect()
+}
+
+/// The expected offsets are what rustc computes, see `tests/pass/tuple_layout.rs` in minimize.
+#[test]
+fn tuple_layouts() {
+    // The last field is never moved, so there is padding after the `u8`.
+    assert_eq!(field_offsets(<(u8, u32)>::get_type()), [0, 4]);
+    assert_eq!(<(u8, u32)>::get_size(), size(8));
+    assert_eq!(<(u8, u32)>::get_align(), align(4));
+
+    assert_eq!(field_offsets(<(u16,)>::get_type()), [0]);
+    assert_eq!(<(u16,)>::get_size(), size(2));
+
+    // The other fields are sorted by alignment, where arrays count as aligned to their size.
+    assert_eq!(field_offsets(<(u8, [u8; 4], u16, u32)>::get_type()), [6, 0, 4, 8]);
+    assert_eq!(<(u8, [u8; 4], u16, u32)>::get_size(), size(12));
+    assert_eq!(field_offsets(<(u16, u8, u64, u32)>::get_type()), [8, 10, 0, 12]);
+    assert_eq!(<(u16, u8, u64, u32)>::get_size(), size(16));
+
+    let twelve = <(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u64)>::get_type();
+    assert_eq!(field_offsets(twelve), [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 16]);
+    assert_eq!(twelve.size::<x86_64>(), size(24));
+}
+
+/// Fills tuple locals of different arities and prints their fields.
+#[test]
+fn tuple_locals() {
+    let locals = [<(u32,)>::get_type(), <(u8, i64)>::get_type(), <(u8, u16, u32)>::get_type()];
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        assign(field(local(0), 0), const_int::<u32>(1)),
+        assign(field(local(1), 0), const_int::<u8>(2)),
+        assign(field(local(1), 1), const_int::<i64>(-3)),
+        assign(field(local(2), 0), const_int::<u8>(4)),
+        assign(field(local(2), 1), const_int::<u16>(5)),
+        assign(field(local(2), 2), const_int::<u32>(6)),
+        print(load(field(local(0), 0)), 1),
+    );
+    let b1 = block!(print(load(field(local(1), 1)), 2));
+    let b2 = block!(print(load(field(local(2), 2)), 3));
+    let b3 = block!(print(load(field(local(2), 0)), 4));
+    let b4 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4]);
+
+    assert_eq!(get_stdout(program(&[f])).unwrap(), &["1", "-3", "6", "4"]);
+}
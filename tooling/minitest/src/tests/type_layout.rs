@@ -0,0 +1,14 @@
+use crate::*;
+
+#[test]
+fn layout_of_ints_and_pointers() {
+    assert_type_layout(<u8>::get_type(), size(1), align(1));
+    assert_type_layout(<i64>::get_type(), size(8), align(8));
+    assert_type_layout(<&u8>::get_type(), size(8), align(8));
+}
+
+#[test]
+fn layout_of_arrays() {
+    assert_type_layout(<[u16; 3]>::get_type(), size(6), align(2));
+    assert_type_layout(<[(); 5]>::get_type(), size(0), align(1));
+}
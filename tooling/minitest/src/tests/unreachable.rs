@@ -11,3 +11,27 @@ fn reach_unreachable() {
     dump_program(p);
     assert_ub(p, "reached unreachable code");
 }
+
+#[test]
+fn call_unreachable_unchecked() {
+    let b0 = block!(unreachable_unchecked(None));
+    let f = function(Ret::No, 0, &[], &[b0]);
+    assert_ub(program(&[f]), "`UnreachableUnchecked` intrinsic was called");
+}
+
+/// Having a block to continue with does not make a difference.
+#[test]
+fn call_unreachable_unchecked_with_next() {
+    let b0 = block!(unreachable_unchecked(Some(1)));
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &[], &[b0, b1]);
+    assert_ub(program(&[f]), "`UnreachableUnchecked` intrinsic was called");
+}
+
+/// Unlike `unreachable_unchecked`, `abort` is not UB.
+#[test]
+fn abort_is_not_ub() {
+    let b0 = block!(abort());
+    let f = function(Ret::No, 0, &[], &[b0]);
+    assert_abort(program(&[f]));
+}
@@ -0,0 +1,56 @@
+use crate::*;
+
+type M = BasicMemory<x86_64>;
+
+/// The type of `Option<(u8, u16)>`, with the tag stored in the first byte.
+fn option_pair_ty() -> Type {
+    let pair_ty = tuple_ty(
+        &[(offset(0), <u8>::get_type()), (offset(2), <u16>::get_type())],
+        size(4),
+        align(2),
+    );
+    let u8_int = IntType { signed: Signedness::Unsigned, size: size(1) };
+    let none =
+        enum_variant(tuple_ty(&[], size(6), align(2)), &[(offset(0), (u8_int, 0.into()))]);
+    let some = enum_variant(
+        tuple_ty(&[(offset(2), pair_ty)], size(6), align(2)),
+        &[(offset(0), (u8_int, 1.into()))],
+    );
+    enum_ty::<u8>(
+        &[(0, none), (1, some)],
+        discriminator_branch::<u8>(
+            offset(0),
+            discriminator_invalid(),
+            &[((0, 1), discriminator_known(0)), ((1, 2), discriminator_known(1))],
+        ),
+        size(6),
+        align(2),
+    )
+}
+
+#[test]
+fn render_option_pair() {
+    let pair = Value::<M>::Tuple(list![Value::Int(3.into()), Value::Int(4.into())]);
+    let some = Value::<M>::Variant {
+        discriminant: 1.into(),
+        data: GcCow::new(Value::Tuple(list![pair])),
+    };
+    assert_eq!(value_to_string::<M>(some, option_pair_ty()), "variant1((3, 4))");
+
+    let none =
+        Value::<M>::Variant { discriminant: 0.into(), data: GcCow::new(Value::Tuple(list![])) };
+    assert_eq!(value_to_string::<M>(none, option_pair_ty()), "variant0");
+}
+
+#[test]
+fn render_uninit_union() {
+    let ty = union_ty(&[(offset(0), <u16>::get_type())], size(2), align(2));
+    let val = Value::<M>::Union(list![list![AbstractByte::Init(1, None), AbstractByte::Uninit]]);
+    assert_eq!(value_to_string::<M>(val, ty), "union{ [0x01, <uninit>] }");
+}
+
+#[test]
+fn render_raw_pointer() {
+    let val = Value::<M>::Ptr(Pointer { addr: 16.into(), provenance: None });
+    assert_eq!(value_to_string::<M>(val, raw_ptr_ty()), "0x10 (no provenance)");
+}
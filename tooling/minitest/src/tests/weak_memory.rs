@@ -0,0 +1,153 @@
+use crate::*;
+
+/// A thread that stores 1 to global `store_to`, then loads global `load_from` into global `load_into`.
+fn store_then_load(
+    store_to: u32,
+    store_ordering: AtomicOrdering,
+    load_from: u32,
+    load_ordering: AtomicOrdering,
+    load_into: u32,
+) -> Function {
+    let locals = [<()>::get_type(), <*const ()>::get_type()];
+    let b0 = block!(atomic_store_ordered(
+        addr_of(global::<u32>(store_to), raw_ptr_ty()),
+        const_int(1u32),
+        store_ordering,
+        1
+    ));
+    let b1 = block!(atomic_load_ordered(
+        global::<u32>(load_into),
+        addr_of(global::<u32>(load_from), raw_ptr_ty()),
+        load_ordering,
+        2
+    ));
+    let b2 = block!(return_());
+    function(Ret::Yes, 1, &locals, &[b0, b1, b2])
+}
+
+/// The "store buffering" litmus test: two threads each store to one global and then load the
+/// other. Returns the program printing the loaded values.
+fn store_buffering(ordering: AtomicOrdering) -> Program {
+    let locals = [<u32>::get_type(), <u32>::get_type()];
+    let b0 = block!(storage_live(0), storage_live(1), spawn(fn_ptr(1), null(), local(0), 1));
+    let b1 = block!(spawn(fn_ptr(2), null(), local(1), 2));
+    let b2 = block!(join(load(local(0)), 3));
+    let b3 = block!(join(load(local(1)), 4));
+    let b4 = block!(print(load(global::<u32>(2)), 5));
+    let b5 = block!(print(load(global::<u32>(3)), 6));
+    let b6 = block!(exit());
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5, b6]);
+
+    // Globals 0 and 1 are `x` and `y`, globals 2 and 3 are `r1` and `r2`.
+    let t1 = store_then_load(0, ordering, 1, ordering, 2);
+    let t2 = store_then_load(1, ordering, 0, ordering, 3);
+    let globals = [global_int::<u32>(); 4];
+    program_with_globals(&[main, t1, t2], &globals)
+}
+
+/// Run `prog` `attempts` times and return whether it ever printed `expected`.
+fn can_print(prog: Program, attempts: usize, expected: &[&str]) -> bool {
+    (0..attempts).any(|_| get_stdout(prog).unwrap() == expected)
+}
+
+#[test]
+fn store_buffering_relaxed() {
+    let p = store_buffering(AtomicOrdering::Relaxed);
+    assert!(can_print(p, 200, &["0", "0"]));
+}
+
+#[test]
+fn store_buffering_seqcst() {
+    let p = store_buffering(AtomicOrdering::SeqCst);
+    assert!(!can_print(p, 200, &["0", "0"]));
+}
+
+/// The "message passing" litmus test: one thread writes the data and then sets a flag, the other
+/// reads the flag and then the data. Returns the program printing the flag and the data.
+fn message_passing(store_ordering: AtomicOrdering, load_ordering: AtomicOrdering) -> Program {
+    let locals = [<u32>::get_type()];
+    let b0 = block!(storage_live(0), spawn(fn_ptr(1), null(), local(0), 1));
+    // Global 0 is the data, global 1 the flag; globals 2 and 3 get the loaded flag and data.
+    let b1 = block!(atomic_load_ordered(
+        global::<u32>(2),
+        addr_of(global::<u32>(1), raw_ptr_ty()),
+        load_ordering,
+        2
+    ));
+    let b2 = block!(atomic_load_ordered(
+        global::<u32>(3),
+        addr_of(global::<u32>(0), raw_ptr_ty()),
+        AtomicOrdering::Relaxed,
+        3
+    ));
+    let b3 = block!(join(load(local(0)), 4));
+    let b4 = block!(print(load(global::<u32>(2)), 5));
+    let b5 = block!(print(load(global::<u32>(3)), 6));
+    let b6 = block!(exit());
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5, b6]);
+
+    let locals = [<()>::get_type(), <*const ()>::get_type()];
+    let b0 = block!(atomic_store_ordered(
+        addr_of(global::<u32>(0), raw_ptr_ty()),
+        const_int(1u32),
+        AtomicOrdering::Relaxed,
+        1
+    ));
+    let b1 = block!(atomic_store_ordered(
+        addr_of(global::<u32>(1), raw_ptr_ty()),
+        const_int(1u32),
+        store_ordering,
+        2
+    ));
+    let b2 = block!(return_());
+    let writer = function(Ret::Yes, 1, &locals, &[b0, b1, b2]);
+
+    let globals = [global_int::<u32>(); 4];
+    program_with_globals(&[main, writer], &globals)
+}
+
+#[test]
+fn message_passing_relaxed() {
+    let p = message_passing(AtomicOrdering::Relaxed, AtomicOrdering::Relaxed);
+    assert!(can_print(p, 200, &["1", "0"]));
+}
+
+#[test]
+fn message_passing_release_acquire() {
+    let p = message_passing(AtomicOrdering::Release, AtomicOrdering::Acquire);
+    assert!(!can_print(p, 200, &["1", "0"]));
+}
+
+/// After joining a thread, its stores must be visible, even to relaxed loads.
+#[test]
+fn join_synchronizes() {
+    let locals = [<u32>::get_type()];
+    let b0 = block!(storage_live(0), spawn(fn_ptr(1), null(), local(0), 1));
+    let b1 = block!(join(load(local(0)), 2));
+    let b2 = block!(atomic_load_ordered(
+        global::<u32>(2),
+        addr_of(global::<u32>(0), raw_ptr_ty()),
+        AtomicOrdering::Relaxed,
+        3
+    ));
+    let b3 = block!(print(load(global::<u32>(2)), 4));
+    let b4 = block!(exit());
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4]);
+
+    let t1 = store_then_load(0, AtomicOrdering::Relaxed, 1, AtomicOrdering::Relaxed, 3);
+    let globals = [global_int::<u32>(); 4];
+    let p = program_with_globals(&[main, t1], &globals);
+    assert!(!can_print(p, 100, &["0"]));
+}
+
+#[test]
+fn atomic_load_release_ill_formed() {
+    let locals = [<u32>::get_type()];
+    let b0 = block!(
+        storage_live(0),
+        atomic_load_ordered(local(0), addr_of(local(0), raw_ptr_ty()), AtomicOrdering::Release, 1)
+    );
+    let b1 = block!(exit());
+    let p = program(&[function(Ret::No, 0, &locals, &[b0, b1])]);
+    assert_ill_formed(p, "IntrinsicOp::AtomicLoad: release ordering");
+}
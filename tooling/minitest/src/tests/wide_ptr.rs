@@ -0,0 +1,224 @@
+use crate::*;
+
+/// A raw pointer to the 5-element `u32` array in local 0, as a `*const [u32]`.
+fn slice_ptr() -> ValueExpr {
+    construct_wide_pointer(
+        addr_of(local(0), raw_ptr_ty()),
+        const_int(5usize),
+        raw_wide_ptr_ty(PointerMetaKind::ElementCount),
+    )
+}
+
+/// The slice behind `slice_ptr`, stored in local 1.
+fn slice_place() -> PlaceExpr {
+    deref(load(local(1)), slice_ty(<u32>::get_type()))
+}
+
+fn slice_locals() -> [Type; 3] {
+    [<[u32; 5]>::get_type(), raw_wide_ptr_ty(PointerMetaKind::ElementCount), <usize>::get_type()]
+}
+
+#[test]
+fn slice_len_and_index() {
+    let locals = slice_locals();
+    let blocks = [
+        block!(
+            storage_live(0),
+            storage_live(1),
+            assign(local(0), array(&[const_int(1u32); 5], <u32>::get_type())),
+            assign(index(local(0), const_int(4usize)), const_int(42u32)),
+            assign(local(1), slice_ptr()),
+            print(len(slice_place()), 1)
+        ),
+        block!(print(load(index(slice_place(), const_int(4usize))), 2)),
+        block!(exit()),
+    ];
+    let p = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_eq!(get_stdout(p).unwrap(), &["5", "42"]);
+}
+
+#[test]
+fn slice_index_out_of_bounds() {
+    let [arr_ty, ptr_ty, _] = slice_locals();
+    let locals = [arr_ty, ptr_ty, <u32>::get_type()];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        assign(local(0), array(&[const_int(1u32); 5], <u32>::get_type())),
+        assign(local(1), slice_ptr()),
+        assign(local(2), load(index(slice_place(), const_int(5usize)))),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ub(p, "out-of-bounds array access");
+}
+
+/// The size of a slice is computed from its length.
+#[test]
+fn size_of_val_slice() {
+    let locals = slice_locals();
+    let blocks = [
+        block!(
+            storage_live(0),
+            storage_live(1),
+            storage_live(2),
+            assign(local(1), slice_ptr()),
+            size_of_val(local(2), slice_ty(<u32>::get_type()), load(local(1)), 1)
+        ),
+        block!(print(load(local(2)), 2)),
+        block!(align_of_val(local(2), slice_ty(<u32>::get_type()), load(local(1)), 3)),
+        block!(print(load(local(2)), 4)),
+        block!(exit()),
+    ];
+    let p = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_eq!(get_stdout(p).unwrap(), &["20", "4"]);
+}
+
+/// A `*const dyn Trait` pointing to `u32` local 0, with the given metadata.
+fn dyn_ptr(meta: ValueExpr) -> ValueExpr {
+    construct_wide_pointer(
+        addr_of(local(0), raw_ptr_ty()),
+        meta,
+        raw_wide_ptr_ty(PointerMetaKind::VTablePointer),
+    )
+}
+
+fn dyn_locals() -> [Type; 2] {
+    [<u32>::get_type(), <usize>::get_type()]
+}
+
+/// The size and alignment of a trait object are read from its vtable.
+#[test]
+fn size_of_val_trait_object() {
+    let locals = dyn_locals();
+    let blocks = [
+        block!(
+            storage_live(0),
+            storage_live(1),
+            size_of_val(local(1), trait_object_ty(), dyn_ptr(vtable_pointer(0)), 1)
+        ),
+        block!(print(load(local(1)), 2)),
+        block!(align_of_val(local(1), trait_object_ty(), dyn_ptr(vtable_pointer(0)), 3)),
+        block!(print(load(local(1)), 4)),
+        block!(exit()),
+    ];
+    let f = function(Ret::No, 0, &locals, &blocks);
+    let vtable = VTable { size: size(4), align: align(4), methods: Map::new() };
+    let p = program_with_vtables(&[f], &[vtable]);
+    assert_eq!(get_stdout(p).unwrap(), &["4", "4"]);
+}
+
+#[test]
+fn size_of_val_invalid_vtable() {
+    let locals = dyn_locals();
+    let blocks = [
+        block!(
+            storage_live(0),
+            storage_live(1),
+            size_of_val(local(1), trait_object_ty(), dyn_ptr(addr_of(local(0), raw_ptr_ty())), 1)
+        ),
+        block!(exit()),
+    ];
+    let p = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_ub(p, "Using a wide pointer whose metadata is not a vtable.");
+}
+
+#[test]
+fn size_of_val_sized_type_wide_ptr() {
+    let locals = slice_locals();
+    let blocks = [
+        block!(
+            storage_live(0),
+            storage_live(2),
+            size_of_val(local(2), <u32>::get_type(), slice_ptr(), 1)
+        ),
+        block!(exit()),
+    ];
+    let p = program(&[function(Ret::No, 0, &locals, &blocks)]);
+    assert_ub(p, "invalid argument for `SizeOfVal` intrinsic: metadata does not match the type");
+}
+
+#[test]
+fn get_thin_pointer_of_thin_pointer() {
+    let locals = [<u32>::get_type(), <*const u32>::get_type()];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(1), get_thin_pointer(addr_of(local(0), raw_ptr_ty()))),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ill_formed(p, "UnOp::GetThinPointer: operand is not a wide pointer");
+}
+
+#[test]
+fn unsized_local() {
+    let locals = [slice_ty(<u32>::get_type())];
+    let p = small_program(&locals, &[]);
+    assert_ill_formed(p, "Type: unsized type where a sized type is required");
+}
+
+/// A method taking a `*const u32` as `self` and printing the pointee.
+fn print_method() -> Function {
+    let locals = [<()>::get_type(), <*const u32>::get_type()];
+    let b0 = block!(print(load(deref(load(local(1)), <u32>::get_type())), 1));
+    let b1 = block!(return_());
+    Function {
+        calling_convention: CallingConvention::Rust,
+        ..function(Ret::Yes, 1, &locals, &[b0, b1])
+    }
+}
+
+/// Calls method 0 of the trait object behind `dyn_ptr(vtable_pointer(0))`, with the data pointer
+/// as `self`.
+fn dyn_call_program(methods: &[(u32, u32)]) -> Program {
+    let locals = [<u32>::get_type(), <()>::get_type()];
+    let call = Terminator::Call {
+        callee: vtable_method_lookup(dyn_ptr(vtable_pointer(0)), 0),
+        arguments: list![by_value(get_thin_pointer(dyn_ptr(vtable_pointer(0))))],
+        ret: local(1),
+        next_block: Some(BbName(Name::from_internal(1))),
+    };
+    let blocks = [
+        block(&[storage_live(0), storage_live(1), assign(local(0), const_int(7u32))], call),
+        block!(exit()),
+    ];
+    let main = function(Ret::No, 0, &locals, &blocks);
+    let methods = methods
+        .iter()
+        .map(|&(method, f)| {
+            (TraitMethodName(Name::from_internal(method)), FnName(Name::from_internal(f)))
+        })
+        .collect();
+    let vtable = VTable { size: size(4), align: align(4), methods };
+    program_with_vtables(&[main, print_method()], &[vtable])
+}
+
+#[test]
+fn dyn_method_call() {
+    let p = dyn_call_program(&[(0, 1)]);
+    assert_eq!(get_stdout(p).unwrap(), &["7"]);
+}
+
+#[test]
+fn dyn_method_missing() {
+    let p = dyn_call_program(&[(1, 1)]);
+    assert_ub(p, "the vtable does not contain the method that is looked up");
+}
+
+#[test]
+fn dyn_method_not_a_function() {
+    let p = dyn_call_program(&[(0, 2)]);
+    assert_ill_formed(p, "Program: vtable method is not a function");
+}
+
+#[test]
+fn vtable_method_lookup_on_slice() {
+    let locals = [<[u32; 5]>::get_type(), Type::Ptr(PtrType::FnPtr(CallingConvention::Rust))];
+    let stmts =
+        [storage_live(0), storage_live(1), assign(local(1), vtable_method_lookup(slice_ptr(), 0))];
+    let p = small_program(&locals, &stmts);
+    assert_ill_formed(
+        p,
+        "ValueExpr::VTableMethodLookup: expression is not a pointer to a trait object",
+    );
+}
@@ -0,0 +1,54 @@
+use crate::*;
+
+/// A global holding `bytes`.
+fn global_bytes(bytes: &[u8]) -> Global {
+    let bytes = bytes.iter().map(|b| Some(*b)).collect();
+    Global { bytes, relocations: list!(), align: Align::ONE }
+}
+
+/// Writes `len` bytes of global 0 to stdout.
+fn write_global(len: usize) -> Function {
+    let data = addr_of(global::<[u8; 6]>(0), <*const u8>::get_type());
+    let b0 = block!(write_bytes_stdout(data, const_int::<usize>(len), 1));
+    let b1 = block!(exit());
+    function(Ret::No, 0, &[], &[b0, b1])
+}
+
+#[test]
+fn write_bytes_hello() {
+    let p = program_with_globals(&[write_global(6)], &[global_bytes(b"hello\n")]);
+    assert_eq!(get_stdout(p).unwrap(), &["hello"]);
+}
+
+/// No line break is added, so consecutive writes end up on the same line.
+#[test]
+fn write_bytes_no_newline() {
+    let data = addr_of(global::<[u8; 6]>(0), <*const u8>::get_type());
+    let b0 = block!(write_bytes_stdout(data, const_int::<usize>(2), 1));
+    let b1 = block!(write_bytes_stdout(data, const_int::<usize>(6), 2));
+    let b2 = block!(exit());
+    let f = function(Ret::No, 0, &[], &[b0, b1, b2]);
+
+    let p = program_with_globals(&[f], &[global_bytes(b"hello\n")]);
+    assert_eq!(get_stdout(p).unwrap(), &["hehello"]);
+}
+
+#[test]
+fn write_bytes_uninit() {
+    let bytes = list![Some(b'h'), None, Some(b'l'), Some(b'l'), Some(b'o'), Some(b'\n')];
+    let global = Global { bytes, relocations: list!(), align: Align::ONE };
+    let p = program_with_globals(&[write_global(6)], &[global]);
+    assert_ub(p, "`WriteBytesStdout` intrinsic: writing uninitialized memory");
+}
+
+#[test]
+fn write_bytes_out_of_bounds() {
+    let p = program_with_globals(&[write_global(7)], &[global_bytes(b"hello\n")]);
+    assert_ub(p, "out-of-bounds memory access");
+}
+
+#[test]
+fn write_bytes_invalid_utf8() {
+    let p = program_with_globals(&[write_global(6)], &[global_bytes(b"he\xffllo")]);
+    assert_ub(p, "`WriteBytesStdout` intrinsic: writing bytes that are not valid UTF-8");
+}
@@ -5,6 +5,8 @@ use crate::build::*;
 pub fn const_int<T: TypeConv + Into<Int>>(int: T) -> ValueExpr {
     ValueExpr::Constant(Constant::Int(int.into()), T::get_type())
 }
+// Like `const_int`, but the value is given as an `Int`.
+// Values that do not fit into `T` make the program ill-formed.
 pub fn const_int_typed<T: TypeConv>(int: Int) -> ValueExpr {
     ValueExpr::Constant(Constant::Int(int), T::get_type())
 }
@@ -26,10 +28,28 @@ pub fn array(args: &[ValueExpr], elem_ty: Type) -> ValueExpr {
     ValueExpr::Tuple(args.iter().cloned().collect(), ty)
 }
 
+/// An array of `n` copies of `elem`, like Rust's `[elem; n]`.
+pub fn array_repeat(elem: ValueExpr, n: impl Into<Int>, elem_ty: Type) -> ValueExpr {
+    let n = n.into();
+    ValueExpr::Tuple(list![elem; n], array_ty(elem_ty, n))
+}
+
+pub fn vector(args: &[ValueExpr], elem_ty: Type) -> ValueExpr {
+    let ty = vector_ty(elem_ty, args.len());
+    ValueExpr::Tuple(args.iter().cloned().collect(), ty)
+}
+
 pub fn variant(discriminant: impl Into<Int>, data: ValueExpr, enum_ty: Type) -> ValueExpr {
     ValueExpr::Variant { discriminant: discriminant.into(), data: GcCow::new(data), enum_ty }
 }
 
+/// The length of the array at `place`, like MIR's `Len`.
+pub fn len(place: PlaceExpr) -> ValueExpr {
+    ValueExpr::Len { place: GcCow::new(place) }
+}
+
+/// Reads the discriminant of the enum at `place`, like Rust's `discriminant_value` intrinsic.
+/// This is the logical discriminant of the variant, not the tag stored in memory.
 pub fn get_discriminant(place: PlaceExpr) -> ValueExpr {
     ValueExpr::GetDiscriminant { place: GcCow::new(place) }
 }
@@ -51,7 +71,36 @@ pub fn addr_of(target: PlaceExpr, ty: Type) -> ValueExpr {
     let Type::Ptr(ptr_ty) = ty else {
         panic!("addr_of requires a Type::Ptr!");
     };
-    ValueExpr::AddrOf { target: GcCow::new(target), ptr_ty }
+    ValueExpr::AddrOf { target: GcCow::new(target), ptr_ty, two_phase: false }
+}
+
+/// A reference to `target`, like `&` / `&mut`. Creating it is UB unless the place is
+/// non-null, aligned and dereferenceable for the size of `pointee_ty`.
+pub fn ref_(target: PlaceExpr, pointee_ty: Type, mutbl: Mutability) -> ValueExpr {
+    let pointee = pointee_ty.layout::<DefaultTarget>();
+    ValueExpr::AddrOf {
+        target: GcCow::new(target),
+        ptr_ty: PtrType::Ref { mutbl, pointee },
+        two_phase: false,
+    }
+}
+
+/// A two-phase mutable reference to `target`, like the `&mut v` in `v.push(v.len())`.
+pub fn two_phase_ref_mut(target: PlaceExpr, pointee_ty: Type) -> ValueExpr {
+    let pointee = pointee_ty.layout::<DefaultTarget>();
+    ValueExpr::AddrOf {
+        target: GcCow::new(target),
+        ptr_ty: PtrType::Ref { mutbl: Mutability::Mutable, pointee },
+        two_phase: true,
+    }
+}
+
+/// A raw pointer to `target`, like `&raw const` / `&raw mut`. MiniRust raw pointers do not
+/// track mutability, and unlike references they do not require the place to be aligned
+/// or dereferenceable.
+pub fn raw_addr_of(target: PlaceExpr) -> ValueExpr {
+    let ptr_ty = PtrType::Raw { meta_kind: PointerMetaKind::None };
+    ValueExpr::AddrOf { target: GcCow::new(target), ptr_ty, two_phase: false }
 }
 
 /// Unary `-` on an integer.
@@ -64,6 +113,9 @@ pub fn int_not(v: ValueExpr) -> ValueExpr {
     ValueExpr::UnOp { operator: UnOp::Int(IntUnOp::Not), operand: GcCow::new(v) }
 }
 
+/// An `as` cast between integer types, e.g. `int_cast::<u8>(v)`.
+/// Narrowing casts truncate and widening casts sign- or zero-extend, depending on the
+/// signedness of the source type.
 pub fn int_cast<T: TypeConv>(v: ValueExpr) -> ValueExpr {
     let Type::Int(t) = T::get_type() else {
         panic!("int operator received non-int type!");
@@ -113,6 +165,9 @@ pub fn mul(l: ValueExpr, r: ValueExpr) -> ValueExpr {
 pub fn div(l: ValueExpr, r: ValueExpr) -> ValueExpr {
     int_binop(IntBinOp::Div, l, r)
 }
+pub fn rem(l: ValueExpr, r: ValueExpr) -> ValueExpr {
+    int_binop(IntBinOp::Rem, l, r)
+}
 pub fn bit_and(l: ValueExpr, r: ValueExpr) -> ValueExpr {
     int_binop(IntBinOp::BitAnd, l, r)
 }
@@ -123,6 +178,9 @@ pub fn bit_xor(l: ValueExpr, r: ValueExpr) -> ValueExpr {
     int_binop(IntBinOp::BitXor, l, r)
 }
 
+// Integer comparisons. The result is a `bool`; whether the comparison is signed
+// is determined by the values of the operands, which must have the same `IntType`.
+// Pointers cannot be compared with these, use `ptr_eq` and `ptr_cmp` instead.
 fn int_rel(op: IntRel, l: ValueExpr, r: ValueExpr) -> ValueExpr {
     ValueExpr::BinOp { operator: BinOp::IntRel(op), left: GcCow::new(l), right: GcCow::new(r) }
 }
@@ -151,6 +209,20 @@ pub fn lt(l: ValueExpr, r: ValueExpr) -> ValueExpr {
     int_rel(IntRel::Lt, l, r)
 }
 
+fn ptr_rel(op: IntRel, l: ValueExpr, r: ValueExpr) -> ValueExpr {
+    ValueExpr::BinOp { operator: BinOp::PtrRel(op), left: GcCow::new(l), right: GcCow::new(r) }
+}
+
+/// Compares the addresses of two pointers for equality, ignoring provenance.
+pub fn ptr_eq(l: ValueExpr, r: ValueExpr) -> ValueExpr {
+    ptr_rel(IntRel::Eq, l, r)
+}
+
+/// Compares the addresses of two pointers with the given relation, ignoring provenance.
+pub fn ptr_cmp(op: IntRel, l: ValueExpr, r: ValueExpr) -> ValueExpr {
+    ptr_rel(op, l, r)
+}
+
 fn bool_binop(op: BoolBinOp, l: ValueExpr, r: ValueExpr) -> ValueExpr {
     ValueExpr::BinOp { operator: BinOp::Bool(op), left: GcCow::new(l), right: GcCow::new(r) }
 }
@@ -177,6 +249,37 @@ pub fn ptr_offset(l: ValueExpr, r: ValueExpr, inbounds: InBounds) -> ValueExpr {
     }
 }
 
+/// A wide pointer of type `ptr_ty` made of the thin pointer `ptr` and the metadata `meta`,
+/// which is a `usize` element count for slices and a vtable pointer for trait objects.
+pub fn construct_wide_pointer(ptr: ValueExpr, meta: ValueExpr, ptr_ty: Type) -> ValueExpr {
+    let Type::Ptr(ptr_ty) = ptr_ty else {
+        panic!("construct_wide_pointer requires a Type::Ptr!");
+    };
+    ValueExpr::BinOp {
+        operator: BinOp::ConstructWidePointer(ptr_ty),
+        left: GcCow::new(ptr),
+        right: GcCow::new(meta),
+    }
+}
+
+/// The thin part of a wide pointer, dropping the metadata.
+pub fn get_thin_pointer(v: ValueExpr) -> ValueExpr {
+    ValueExpr::UnOp { operator: UnOp::GetThinPointer, operand: GcCow::new(v) }
+}
+
+/// A pointer to the `x`th vtable of the program, to be used as wide pointer metadata.
+pub fn vtable_pointer(x: u32) -> ValueExpr {
+    ValueExpr::Constant(Constant::VTablePointer(VTableName(Name::from_internal(x))), raw_ptr_ty())
+}
+
+/// The `method`th method in the vtable of the trait object pointer `ptr`, as a function pointer.
+pub fn vtable_method_lookup(ptr: ValueExpr, method: u32) -> ValueExpr {
+    ValueExpr::VTableMethodLookup {
+        expression: GcCow::new(ptr),
+        method: TraitMethodName(Name::from_internal(method)),
+    }
+}
+
 pub fn local(x: u32) -> PlaceExpr {
     PlaceExpr::Local(LocalName(Name::from_internal(x)))
 }
@@ -184,7 +287,7 @@ pub fn local(x: u32) -> PlaceExpr {
 pub fn global<T: TypeConv>(x: u32) -> PlaceExpr {
     let relocation = Relocation { name: GlobalName(Name::from_internal(x)), offset: Size::ZERO };
 
-    let ptr_type = Type::Ptr(PtrType::Raw);
+    let ptr_type = raw_ptr_ty();
 
     deref(ValueExpr::Constant(Constant::GlobalPointer(relocation), ptr_type), T::get_type())
 }
@@ -87,6 +87,23 @@ pub fn function(ret: Ret, num_args: usize, locals: &[Type], bbs: &[BasicBlock])
     }
 }
 
+/// Builds a function from its signature and the types of its other locals.
+/// Locals are numbered by role:
+///   _0 is the return local of type `ret`,
+///   _1 .. _n are the locals of the `args`,
+///   and the `temps` come after that.
+pub fn function_builder(args: &[Type], ret: Type, temps: &[Type], bbs: &[BasicBlock]) -> Function {
+    let locals: Vec<Type> =
+        std::iter::once(ret).chain(args.iter().copied()).chain(temps.iter().copied()).collect();
+    function(Ret::Yes, args.len(), &locals, bbs)
+}
+
+/// The function that panics call. Panics do not unwind (like with `-Cpanic=abort`),
+/// so this just aborts the program.
+pub fn panic_fn() -> Function {
+    function(Ret::No, 0, &[], &[block!(abort())])
+}
+
 pub fn block(statements: &[Statement], terminator: Terminator) -> BasicBlock {
     BasicBlock { statements: statements.iter().copied().collect(), terminator }
 }
@@ -123,3 +140,19 @@ pub macro block {
         block!(@{$($stmts),*, $stmt} $($rest),*)
     },
 }
+
+// basic_blocks!(label1 => bb1, label2 => bb2, ...)
+// evaluates to the array [bb1, bb2, ...]. Within all the blocks, each label is a `u32` variable
+// holding the index of its block, so blocks can refer to each other (also forwards) by name,
+// like `goto(label2)`, instead of by manually counted indices.
+// Labels shadow builder functions, so do not name a block e.g. `exit`.
+pub macro basic_blocks($($label:ident => $block:expr),* $(,)?) {{
+    let mut _next_block: u32 = 0;
+    $(
+        let $label: u32 = _next_block;
+        // Not every label is jumped to, e.g. the one of the entry block.
+        let _ = $label;
+        _next_block += 1;
+    )*
+    [$($block),*]
+}}
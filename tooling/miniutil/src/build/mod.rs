@@ -72,7 +72,21 @@ pub fn program_with_globals(fns: &[Function], globals: &[Global]) -> Program {
         })
         .collect();
 
-    Program { functions, start: FnName(Name::from_internal(0)), globals }
+    Program { functions, start: FnName(Name::from_internal(0)), globals, vtables: Map::new() }
+}
+
+// Like `program`, but with the given vtables; the `i`th vtable can be referred to as `vtable_pointer(i)`.
+pub fn program_with_vtables(fns: &[Function], vtables: &[VTable]) -> Program {
+    let vtables: Map<VTableName, VTable> = vtables
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            let name = VTableName(Name::from_internal(i as _));
+            (name, *v)
+        })
+        .collect();
+
+    Program { vtables, ..program(fns) }
 }
 
 // The first function in `fns` is the start function of the program.
@@ -4,18 +4,79 @@ pub fn assign(destination: PlaceExpr, source: ValueExpr) -> Statement {
     Statement::Assign { destination, source }
 }
 
+/// Writes a tuple of type `ty` to `destination`, given as a list of `(field index, value)`.
+/// Every field of `ty` must be given exactly once, in any order.
+pub fn assign_aggregate(
+    destination: PlaceExpr,
+    ty: Type,
+    fields: &[(u32, ValueExpr)],
+) -> Statement {
+    let mut fields = fields.to_vec();
+    fields.sort_by_key(|(idx, _val)| *idx);
+    assert!(
+        fields.iter().enumerate().all(|(i, (idx, _val))| i == *idx as usize),
+        "assign_aggregate: every field must be given exactly once"
+    );
+    let vals: Vec<ValueExpr> = fields.into_iter().map(|(_idx, val)| val).collect();
+    assign(destination, tuple(&vals, ty))
+}
+
+/// Writes the variant of `enum_ty` with the given discriminant and fields to `destination`.
+/// This also writes the tag of the variant.
+pub fn assign_enum(
+    destination: PlaceExpr,
+    enum_ty: Type,
+    discriminant: impl Into<Int>,
+    fields: &[ValueExpr],
+) -> Statement {
+    let discriminant = discriminant.into();
+    let Type::Enum { variants, .. } = enum_ty else {
+        panic!("assign_enum: not an enum type");
+    };
+    let data_ty = variants.get(discriminant).expect("assign_enum: invalid discriminant").ty;
+    assign(destination, variant(discriminant, tuple(fields, data_ty), enum_ty))
+}
+
 pub fn set_discriminant(destination: PlaceExpr, value: impl Into<Int>) -> Statement {
     Statement::SetDiscriminant { destination, value: value.into() }
 }
 
-pub fn validate(place: PlaceExpr, fn_entry: bool) -> Statement {
-    Statement::Validate { place, fn_entry }
+pub fn validate(place: PlaceExpr, kind: RetagKind) -> Statement {
+    Statement::Validate { place, kind }
 }
 
 pub fn storage_live(x: u32) -> Statement {
     Statement::StorageLive(LocalName(Name::from_internal(x)))
 }
 
+/// Like Rust's `let`: makes local `x` live and initializes it with `val`.
+pub fn let_(x: u32, val: ValueExpr) -> [Statement; 2] {
+    [storage_live(x), assign(local(x), val)]
+}
+
 pub fn storage_dead(x: u32) -> Statement {
     Statement::StorageDead(LocalName(Name::from_internal(x)))
 }
+
+pub fn deinit(place: PlaceExpr) -> Statement {
+    Statement::Deinit { place }
+}
+
+/// Moves `source` to `destination`: copies the value and then de-initializes `source`,
+/// so that reading it afterwards is UB. Use `assign(destination, load(source))` for a copy.
+pub fn assign_move(destination: PlaceExpr, source: PlaceExpr) -> [Statement; 2] {
+    [assign(destination, load(source)), deinit(source)]
+}
+
+/// `ptr::read`: loads a value of type `ty` through the raw pointer `ptr` into `destination`.
+/// The pointer must be aligned and dereferenceable, and the value must be valid for `ty`.
+pub fn ptr_read(destination: PlaceExpr, ptr: ValueExpr, ty: Type) -> Statement {
+    assign(destination, load(deref(ptr, ty)))
+}
+
+/// `ptr::write`: stores `val` of type `ty` through the raw pointer `ptr`.
+/// The pointer must be aligned and dereferenceable. MiniRust has no destructors,
+/// so just like in Rust the old value is overwritten without being dropped.
+pub fn ptr_write(ptr: ValueExpr, val: ValueExpr, ty: Type) -> Statement {
+    assign(deref(ptr, ty), val)
+}
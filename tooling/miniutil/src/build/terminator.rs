@@ -12,21 +12,48 @@ pub fn if_(condition: ValueExpr, then_blk: u32, else_blk: u32) -> Terminator {
     }
 }
 
+/// Switches on an integer. Switches on a Boolean (cast to an integer) are canonicalized,
+/// see `canonicalize_switch`.
 pub fn switch_int<T: Clone + Into<Int>>(
     value: ValueExpr,
     cases: &[(T, u32)],
     fallback: u32,
 ) -> Terminator {
-    Terminator::Switch {
+    canonicalize_switch(Terminator::Switch {
         value,
         cases: cases
             .into_iter()
             .map(|(case, successor)| (case.clone().into(), BbName(Name::from_internal(*successor))))
             .collect(),
         fallback: BbName(Name::from_internal(fallback)),
+    })
+}
+
+/// Brings a switch on a Boolean (cast to an integer) into the form generated by `if_`:
+/// a single case for `1` (true), and the fallback for `0` (false).
+/// All other terminators are returned unchanged.
+pub fn canonicalize_switch(terminator: Terminator) -> Terminator {
+    let Terminator::Switch { value, cases, fallback } = terminator else {
+        return terminator;
+    };
+    let ValueExpr::UnOp { operator: UnOp::Cast(CastOp::BoolToInt(_)), .. } = value else {
+        return terminator;
+    };
+    let then_blk = cases.get(Int::ONE).unwrap_or(fallback);
+    let else_blk = cases.get(Int::ZERO).unwrap_or(fallback);
+    Terminator::Switch {
+        value,
+        cases: [(Int::ONE, then_blk)].into_iter().collect(),
+        fallback: else_blk,
     }
 }
 
+/// Checks that `terminator` is not a switch on a Boolean, or one in canonical form
+/// (see `canonicalize_switch`).
+pub fn is_canonical_switch(terminator: Terminator) -> bool {
+    canonicalize_switch(terminator) == terminator
+}
+
 pub fn unreachable() -> Terminator {
     Terminator::Unreachable
 }
@@ -57,6 +84,47 @@ pub fn assume(val: ValueExpr, next: u32) -> Terminator {
     }
 }
 
+/// Raises UB, like `core::hint::unreachable_unchecked`. Whether there is a `next` block does
+/// not matter, since execution never gets there.
+pub fn unreachable_unchecked(next: Option<u32>) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::UnreachableUnchecked,
+        arguments: list![],
+        ret: zst_place(),
+        next_block: next.map(|x| BbName(Name::from_internal(x))),
+    }
+}
+
+/// Stores `cond` in `dest`, with the hint that it is likely `true`.
+pub fn likely(dest: PlaceExpr, cond: ValueExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::Likely,
+        arguments: list![cond],
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+/// Stores `cond` in `dest`, with the hint that it is likely `false`.
+pub fn unlikely(dest: PlaceExpr, cond: ValueExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::Unlikely,
+        arguments: list![cond],
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+/// Stores `val` in `dest`, hiding it from optimizations.
+pub fn black_box(dest: PlaceExpr, val: ValueExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::BlackBox,
+        arguments: list![val],
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
 pub fn print(arg: ValueExpr, next: u32) -> Terminator {
     Terminator::Intrinsic {
         intrinsic: IntrinsicOp::PrintStdout,
@@ -75,6 +143,26 @@ pub fn eprint(arg: ValueExpr, next: u32) -> Terminator {
     }
 }
 
+/// Writes the `len` bytes behind `data` to stdout.
+pub fn write_bytes_stdout(data: ValueExpr, len: ValueExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::WriteBytesStdout,
+        arguments: list![data, len],
+        ret: zst_place(),
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+/// Reads the next byte of input into `dest`, which must be an `i32`; -1 means end of input.
+pub fn read_stdin(dest: PlaceExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::ReadStdin,
+        arguments: list![],
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
 pub fn allocate(size: ValueExpr, align: ValueExpr, ret_place: PlaceExpr, next: u32) -> Terminator {
     Terminator::Intrinsic {
         intrinsic: IntrinsicOp::Allocate,
@@ -93,6 +181,60 @@ pub fn deallocate(ptr: ValueExpr, size: ValueExpr, align: ValueExpr, next: u32)
     }
 }
 
+/// Copies `count` values of type `ty` from `src` to `dst`.
+pub fn copy_nonoverlapping(
+    src: ValueExpr,
+    dst: ValueExpr,
+    count: ValueExpr,
+    ty: Type,
+    next: u32,
+) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::CopyNonOverlapping { ty },
+        arguments: list![src, dst, count],
+        ret: zst_place(),
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+/// Exchanges `count` values of type `ty` between `ptr_a` and `ptr_b`.
+pub fn swap(
+    ptr_a: ValueExpr,
+    ptr_b: ValueExpr,
+    count: ValueExpr,
+    ty: Type,
+    next: u32,
+) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::MemSwap { ty },
+        arguments: list![ptr_a, ptr_b, count],
+        ret: zst_place(),
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+/// Stores whether `ptr` points to a live allocation in `dest`.
+/// This needs `RunConfig::test_intrinsics`.
+pub fn is_allocated(dest: PlaceExpr, ptr: ValueExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::IsAllocated,
+        arguments: list![ptr],
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+/// Stores the number of stack frames of the current thread in `dest`, which must be a `usize`.
+/// This needs `RunConfig::test_intrinsics`.
+pub fn frame_depth(dest: PlaceExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::FrameDepth,
+        arguments: list![],
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
 pub fn exit() -> Terminator {
     Terminator::Intrinsic {
         intrinsic: IntrinsicOp::Exit,
@@ -102,6 +244,108 @@ pub fn exit() -> Terminator {
     }
 }
 
+/// Like `exit`, but with an exit code, as in `std::process::exit(code)`.
+pub fn exit_with_code(code: ValueExpr) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::Exit,
+        arguments: list![code],
+        ret: zst_place(),
+        next_block: None,
+    }
+}
+
+/// Writes an arbitrary integer in `min..=max` to `dest`.
+pub fn nondet(dest: PlaceExpr, min: impl Into<Int>, max: impl Into<Int>, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::Nondet { min: min.into(), max: max.into() },
+        arguments: list![],
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+/// Converts the integer `val` to the integer type of the first field of `dest`, which must be
+/// a pair of that integer type and `bool`. The `bool` is set to whether `val` fits.
+pub fn try_int_cast(dest: PlaceExpr, val: ValueExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::TryIntCast,
+        arguments: list![val],
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+/// Converts the integer `val` to the integer type of `dest`, clamping it to the range of that type.
+pub fn saturating_int_cast(dest: PlaceExpr, val: ValueExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::SaturatingIntCast,
+        arguments: list![val],
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+pub fn rotate_left(dest: PlaceExpr, val: ValueExpr, amount: ValueExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::RotateLeft,
+        arguments: list![val, amount],
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+pub fn rotate_right(dest: PlaceExpr, val: ValueExpr, amount: ValueExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::RotateRight,
+        arguments: list![val, amount],
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+pub fn reverse_bits(dest: PlaceExpr, val: ValueExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::ReverseBits,
+        arguments: list![val],
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+pub fn byte_swap(dest: PlaceExpr, val: ValueExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::ByteSwap,
+        arguments: list![val],
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+/// Loads the value of type `dest` from `ptr` into `dest`, replacing uninitialized bytes
+/// by arbitrary initialized ones.
+pub fn freeze(dest: PlaceExpr, ptr: ValueExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::Freeze,
+        arguments: list![ptr],
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+/// Calls the function `f`, which must be a `panic_fn`.
+pub fn panic(f: u32) -> Terminator {
+    Terminator::Call { callee: fn_ptr(f), arguments: list![], ret: zst_place(), next_block: None }
+}
+
+pub fn abort() -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::Abort,
+        arguments: list![],
+        ret: zst_place(),
+        next_block: None,
+    }
+}
+
 pub fn return_() -> Terminator {
     Terminator::Return
 }
@@ -124,9 +368,55 @@ pub fn join(thread_id: ValueExpr, next: u32) -> Terminator {
     }
 }
 
+pub fn current_thread_id(ret: PlaceExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::CurrentThreadId,
+        arguments: list!(),
+        ret,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+/// Stores a pointer to the current thread's instance of the thread-local global `global` in `ret`.
+pub fn thread_local_addr(ret: PlaceExpr, global: u32, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::ThreadLocalAddr { global: GlobalName(Name::from_internal(global)) },
+        arguments: list!(),
+        ret,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+pub fn park(next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::Park,
+        arguments: list!(),
+        ret: zst_place(),
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+pub fn unpark(thread_id: ValueExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::Unpark,
+        arguments: list!(thread_id),
+        ret: zst_place(),
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
 pub fn atomic_store(ptr: ValueExpr, src: ValueExpr, next: u32) -> Terminator {
+    atomic_store_ordered(ptr, src, AtomicOrdering::SeqCst, next)
+}
+
+pub fn atomic_store_ordered(
+    ptr: ValueExpr,
+    src: ValueExpr,
+    ordering: AtomicOrdering,
+    next: u32,
+) -> Terminator {
     Terminator::Intrinsic {
-        intrinsic: IntrinsicOp::AtomicStore,
+        intrinsic: IntrinsicOp::AtomicStore(ordering),
         arguments: list!(ptr, src),
         ret: zst_place(),
         next_block: Some(BbName(Name::from_internal(next))),
@@ -134,8 +424,17 @@ pub fn atomic_store(ptr: ValueExpr, src: ValueExpr, next: u32) -> Terminator {
 }
 
 pub fn atomic_load(dest: PlaceExpr, ptr: ValueExpr, next: u32) -> Terminator {
+    atomic_load_ordered(dest, ptr, AtomicOrdering::SeqCst, next)
+}
+
+pub fn atomic_load_ordered(
+    dest: PlaceExpr,
+    ptr: ValueExpr,
+    ordering: AtomicOrdering,
+    next: u32,
+) -> Terminator {
     Terminator::Intrinsic {
-        intrinsic: IntrinsicOp::AtomicLoad,
+        intrinsic: IntrinsicOp::AtomicLoad(ordering),
         arguments: list!(ptr),
         ret: dest,
         next_block: Some(BbName(Name::from_internal(next))),
@@ -182,6 +481,23 @@ pub fn compare_exchange(
     }
 }
 
+/// Like `compare_exchange`, storing the previous value in `dest_prev`, but also stores whether
+/// the exchange happened in `dest_ok`. Returns the terminator and a statement computing `dest_ok`,
+/// which has to be the first statement of block `next`.
+/// That statement evaluates `current` again, so `current` must not read from `ptr`.
+pub fn compare_exchange_val(
+    dest_prev: PlaceExpr,
+    dest_ok: PlaceExpr,
+    ptr: ValueExpr,
+    current: ValueExpr,
+    next_val: ValueExpr,
+    next: u32,
+) -> (Terminator, Statement) {
+    let cas = compare_exchange(dest_prev, ptr, current, next_val, next);
+    let ok = assign(dest_ok, eq(load(dest_prev), current));
+    (cas, ok)
+}
+
 pub fn expose_provenance(dest: PlaceExpr, ptr: ValueExpr, next: u32) -> Terminator {
     Terminator::Intrinsic {
         intrinsic: IntrinsicOp::PointerExposeProvenance,
@@ -200,6 +516,65 @@ pub fn with_exposed_provenance(dest: PlaceExpr, addr: ValueExpr, next: u32) -> T
     }
 }
 
+/// Stores the result of `ptr_guaranteed_cmp(l, r)` in `dest`, which must have type `u8`.
+pub fn ptr_guaranteed_cmp(dest: PlaceExpr, l: ValueExpr, r: ValueExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::PtrGuaranteedCmp,
+        arguments: list![l, r],
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+/// Stores the offset of the field at `path` inside `ty` in `dest`.
+pub fn offset_of(dest: PlaceExpr, ty: Type, path: &[u32], next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::OffsetOf { ty, path: path.iter().map(|&f| Int::from(f)).collect() },
+        arguments: list![],
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+pub fn size_of(dest: PlaceExpr, ty: Type, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::SizeOf { ty },
+        arguments: list![],
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+pub fn align_of(dest: PlaceExpr, ty: Type, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::AlignOf { ty },
+        arguments: list![],
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+/// Stores the size of the value of type `ty` behind `ptr` in `dest`.
+/// For unsized types, this is computed from the metadata of the wide pointer `ptr`.
+pub fn size_of_val(dest: PlaceExpr, ty: Type, ptr: ValueExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::SizeOfVal { ty },
+        arguments: list![ptr],
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+/// Stores the alignment of the value of type `ty` behind `ptr` in `dest`.
+pub fn align_of_val(dest: PlaceExpr, ty: Type, ptr: ValueExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::AlignOfVal { ty },
+        arguments: list![ptr],
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
 pub fn lock_create(ret: PlaceExpr, next: u32) -> Terminator {
     Terminator::Intrinsic {
         intrinsic: IntrinsicOp::Lock(IntrinsicLockOp::Create),
@@ -226,3 +601,78 @@ pub fn lock_release(lock_id: ValueExpr, next: u32) -> Terminator {
         next_block: Some(BbName(Name::from_internal(next))),
     }
 }
+
+/// Applies `op` lane-wise to the vectors `l` and `r`.
+pub fn simd_bin_op(
+    op: IntBinOp,
+    dest: PlaceExpr,
+    l: ValueExpr,
+    r: ValueExpr,
+    next: u32,
+) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::SimdBinOp(op),
+        arguments: list!(l, r),
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+/// Compares the vectors `l` and `r` lane-wise, storing a mask of -1/0 lanes in `dest`.
+pub fn simd_rel(
+    rel: IntRel,
+    dest: PlaceExpr,
+    l: ValueExpr,
+    r: ValueExpr,
+    next: u32,
+) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::SimdIntRel(rel),
+        arguments: list!(l, r),
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+/// Stores lane `idx` of the vector `v` in `dest`.
+pub fn simd_extract(dest: PlaceExpr, v: ValueExpr, idx: ValueExpr, next: u32) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::SimdExtract,
+        arguments: list!(v, idx),
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+/// Stores a copy of the vector `v` with lane `idx` replaced by `val` in `dest`.
+pub fn simd_insert(
+    dest: PlaceExpr,
+    v: ValueExpr,
+    idx: ValueExpr,
+    val: ValueExpr,
+    next: u32,
+) -> Terminator {
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::SimdInsert,
+        arguments: list!(v, idx, val),
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+/// Stores the vector built from the lanes of `l` and `r` picked by `indices` in `dest`.
+pub fn simd_shuffle(
+    dest: PlaceExpr,
+    l: ValueExpr,
+    r: ValueExpr,
+    indices: &[u32],
+    next: u32,
+) -> Terminator {
+    let indices: Vec<_> = indices.iter().map(|&i| const_int::<u32>(i)).collect();
+    Terminator::Intrinsic {
+        intrinsic: IntrinsicOp::SimdShuffle,
+        arguments: list!(l, r, array(&indices, <u32>::get_type())),
+        ret: dest,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
@@ -5,6 +5,8 @@ pub fn layout(size: Size, align: Align) -> Layout {
         size,
         align,
         inhabited: true, // currently everything is inhabited (enums don't exist yet).
+        freeze: true,
+        meta_kind: PointerMetaKind::None,
     }
 }
 
@@ -29,11 +31,16 @@ pub fn box_ty(pointee: Layout) -> Type {
 }
 
 pub fn raw_ptr_ty() -> Type {
-    Type::Ptr(PtrType::Raw)
+    Type::Ptr(PtrType::Raw { meta_kind: PointerMetaKind::None })
+}
+
+/// A raw pointer carrying metadata of the given kind, like `*const [T]` or `*const dyn Trait`.
+pub fn raw_wide_ptr_ty(meta_kind: PointerMetaKind) -> Type {
+    Type::Ptr(PtrType::Raw { meta_kind })
 }
 
 pub fn tuple_ty(f: &[(Offset, Type)], size: Size, align: Align) -> Type {
-    Type::Tuple { fields: f.iter().copied().collect(), size, align }
+    Type::Tuple { fields: f.iter().copied().collect(), size, align, valid_range: None }
 }
 
 pub fn union_ty(f: &[(Offset, Type)], size: Size, align: Align) -> Type {
@@ -45,6 +52,23 @@ pub fn array_ty(elem: Type, count: impl Into<Int>) -> Type {
     Type::Array { elem: GcCow::new(elem), count: count.into() }
 }
 
+/// The unsized type `[elem]`; it can only be used behind pointers.
+pub fn slice_ty(elem: Type) -> Type {
+    Type::Slice { elem: GcCow::new(elem) }
+}
+
+/// The unsized type of a trait object, `dyn Trait`; it can only be used behind pointers.
+pub fn trait_object_ty() -> Type {
+    Type::TraitObject
+}
+
+pub fn vector_ty(elem: Type, count: impl Into<Int>) -> Type {
+    let Type::Int(elem) = elem else {
+        panic!("vector_ty requires integer lanes!");
+    };
+    Type::Vector { elem, count: count.into() }
+}
+
 pub fn enum_variant(ty: Type, tagger: &[(Offset, (IntType, Int))]) -> Variant {
     Variant { ty, tagger: tagger.iter().copied().collect() }
 }
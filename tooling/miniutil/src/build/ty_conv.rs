@@ -87,3 +87,54 @@ impl TypeConv for () {
         tuple_ty(&[], size(0), align(1))
     }
 }
+
+/// Lays out a tuple like rustc's default layout does if none of the fields has a niche:
+/// the last field stays last, since it could be unsized in general. The other fields are sorted by
+/// decreasing alignment, where fields count as aligned to their size if that is a larger power of
+/// two, and otherwise stay in declaration order.
+/// Fields with a niche (like `bool` or references) may be placed differently by rustc.
+fn default_tuple_ty(fields: &[Type]) -> Type {
+    let layouts: Vec<Layout> = fields.iter().map(|ty| ty.layout::<DefaultTarget>()).collect();
+    let bytes = |x: Int| x.try_to_usize().unwrap();
+    let group = |l: &Layout| bytes(l.align.bytes()).max(bytes(l.size.bytes())).trailing_zeros();
+    let mut memory_order: Vec<usize> = (0..fields.len()).collect();
+    let reorderable = fields.len().saturating_sub(1);
+    memory_order[..reorderable].sort_by_key(|&i| std::cmp::Reverse(group(&layouts[i])));
+
+    let mut offsets = vec![0; fields.len()];
+    let mut end: usize = 0;
+    let mut tuple_align: usize = 1;
+    for i in memory_order {
+        let field_align = bytes(layouts[i].align.bytes());
+        offsets[i] = end.next_multiple_of(field_align);
+        end = offsets[i] + bytes(layouts[i].size.bytes());
+        tuple_align = tuple_align.max(field_align);
+    }
+
+    let fields: Vec<(Offset, Type)> =
+        fields.iter().zip(offsets).map(|(ty, off)| (offset(off), *ty)).collect();
+    tuple_ty(&fields, size(end.next_multiple_of(tuple_align)), align(tuple_align))
+}
+
+macro_rules! type_conv_tuple_impl {
+    ($($t:ident),*) => {
+        impl<$($t: TypeConv),*> TypeConv for ($($t,)*) {
+            fn get_type() -> Type {
+                default_tuple_ty(&[$($t::get_type()),*])
+            }
+        }
+    };
+}
+
+type_conv_tuple_impl!(A);
+type_conv_tuple_impl!(A, B);
+type_conv_tuple_impl!(A, B, C);
+type_conv_tuple_impl!(A, B, C, D);
+type_conv_tuple_impl!(A, B, C, D, E);
+type_conv_tuple_impl!(A, B, C, D, E, F);
+type_conv_tuple_impl!(A, B, C, D, E, F, G);
+type_conv_tuple_impl!(A, B, C, D, E, F, G, H);
+type_conv_tuple_impl!(A, B, C, D, E, F, G, H, I);
+type_conv_tuple_impl!(A, B, C, D, E, F, G, H, I, J);
+type_conv_tuple_impl!(A, B, C, D, E, F, G, H, I, J, K);
+type_conv_tuple_impl!(A, B, C, D, E, F, G, H, I, J, K, L);
@@ -0,0 +1,109 @@
+use super::*;
+
+// Describes the first difference between `a` and `b`, in the syntax of `fmt_program`.
+// Functions are compared in the order of their names, and within a function the locals come
+// before the blocks. Returns an empty string if the programs are equal.
+pub fn program_diff(a: Program, b: Program) -> String {
+    if a == b {
+        return String::new();
+    }
+    if a.start != b.start {
+        return format!(
+            "start function: {} vs {}",
+            fmt_fn_name(a.start),
+            fmt_fn_name(b.start)
+        );
+    }
+
+    let mut fn_names: Vec<FnName> =
+        a.functions.iter().chain(b.functions.iter()).map(|(k, _)| k).collect();
+    fn_names.sort_by_key(|FnName(name)| *name);
+    fn_names.dedup();
+    for fn_name in fn_names {
+        let name = fmt_fn_name(fn_name);
+        match (a.functions.get(fn_name), b.functions.get(fn_name)) {
+            (Some(fa), Some(fb)) if fa != fb => return function_diff(&name, fa, fb),
+            (Some(_), None) => return format!("{name}: only in the first program"),
+            (None, Some(_)) => return format!("{name}: only in the second program"),
+            _ => {}
+        }
+    }
+
+    let mut global_names: Vec<GlobalName> =
+        a.globals.iter().chain(b.globals.iter()).map(|(k, _)| k).collect();
+    global_names.sort_by_key(|GlobalName(name)| *name);
+    global_names.dedup();
+    for global_name in global_names {
+        let ga = a.globals.get(global_name);
+        let gb = b.globals.get(global_name);
+        if ga != gb {
+            let name = fmt_global_name(global_name);
+            let fmt = |g: Option<Global>| match g {
+                Some(g) => fmt_global(global_name, g).trim().to_string(),
+                None => "<missing>".to_string(),
+            };
+            return format!("{name}:\n  - {}\n  + {}", fmt(ga), fmt(gb));
+        }
+    }
+
+    unreachable!("programs differ, but no difference was found")
+}
+
+fn function_diff(name: &str, a: Function, b: Function) -> String {
+    let mut comptypes = Vec::new();
+    if a.args != b.args || a.ret != b.ret || a.calling_convention != b.calling_convention {
+        return format!("{name}: signatures differ");
+    }
+
+    let mut locals: Vec<LocalName> =
+        a.locals.iter().chain(b.locals.iter()).map(|(k, _)| k).collect();
+    locals.sort_by_key(|LocalName(name)| *name);
+    locals.dedup();
+    for local in locals {
+        let (ta, tb) = (a.locals.get(local), b.locals.get(local));
+        if ta != tb {
+            let mut fmt = |t: Option<Type>| match t {
+                Some(t) => fmt_type(t, &mut comptypes).to_string(),
+                None => "<missing>".to_string(),
+            };
+            let local = fmt_local_name(local);
+            return format!("{name}, local {local}:\n  - {}\n  + {}", fmt(ta), fmt(tb));
+        }
+    }
+
+    if a.start != b.start {
+        let (sa, sb) = (fmt_bb_name(a.start), fmt_bb_name(b.start));
+        return format!("{name}: start block {sa} vs {sb}");
+    }
+
+    let mut bb_names: Vec<BbName> =
+        a.blocks.iter().chain(b.blocks.iter()).map(|(k, _)| k).collect();
+    bb_names.sort_by_key(|BbName(name)| *name);
+    bb_names.dedup();
+    for bb_name in bb_names {
+        let bb = fmt_bb_name(bb_name);
+        let (blk_a, blk_b) = match (a.blocks.get(bb_name), b.blocks.get(bb_name)) {
+            (Some(blk_a), Some(blk_b)) if blk_a != blk_b => (blk_a, blk_b),
+            (Some(_), None) => return format!("{name}, {bb}: only in the first program"),
+            (None, Some(_)) => return format!("{name}, {bb}: only in the second program"),
+            _ => continue,
+        };
+        let stmts_a: Vec<Statement> = blk_a.statements.iter().collect();
+        let stmts_b: Vec<Statement> = blk_b.statements.iter().collect();
+        for i in 0..stmts_a.len().max(stmts_b.len()) {
+            let (sa, sb) = (stmts_a.get(i).copied(), stmts_b.get(i).copied());
+            if sa != sb {
+                let mut fmt = |s: Option<Statement>| match s {
+                    Some(s) => fmt_statement(s, &mut comptypes).trim().to_string(),
+                    None => "<missing>".to_string(),
+                };
+                return format!("{name}, {bb}, statement {i}:\n  - {}\n  + {}", fmt(sa), fmt(sb));
+            }
+        }
+        let ta = fmt_terminator(blk_a.terminator, &mut comptypes);
+        let tb = fmt_terminator(blk_b.terminator, &mut comptypes);
+        return format!("{name}, {bb}, terminator:\n  - {}\n  + {}", ta.trim(), tb.trim());
+    }
+
+    unreachable!("functions differ, but no difference was found")
+}
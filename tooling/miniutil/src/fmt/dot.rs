@@ -0,0 +1,49 @@
+use super::*;
+
+// Formats the control-flow graph of a function in the Graphviz DOT format.
+// Every basic block is a node labeled with its statements and terminator (as in `fmt_program`),
+// and every possible jump is an edge labeled by the kind of the jump.
+pub fn to_dot(f: Function) -> String {
+    // The composite types are not included in the graph, they are only referred to by name.
+    let mut comptypes: Vec<CompType> = Vec::new();
+
+    let mut blocks: Vec<(BbName, BasicBlock)> = f.blocks.iter().collect();
+    blocks.sort_by_key(|(BbName(name), _block)| *name);
+
+    let mut out = String::from("digraph cfg {\n  node [shape=box, fontname=monospace];\n");
+    for (bb_name, bb) in blocks {
+        let name = fmt_bb_name(bb_name);
+        let label = fmt_bb(bb_name, bb, f.start == bb_name, &mut comptypes);
+        let style = if f.start == bb_name { ", style=bold" } else { "" };
+        out += &format!("  {name} [label=\"{}\"{style}];\n", escape(&label));
+        for (succ, edge_label) in successors(bb.terminator) {
+            out += &format!("  {name} -> {} [label=\"{edge_label}\"];\n", fmt_bb_name(succ));
+        }
+    }
+    out += "}\n";
+    out
+}
+
+// The successors of a terminator, with the label of the respective edge.
+// MiniRust has no unwinding, so calls only have a normal successor.
+fn successors(t: Terminator) -> Vec<(BbName, String)> {
+    match t {
+        Terminator::Goto(bb) => vec![(bb, "goto".to_string())],
+        Terminator::Switch { cases, fallback, .. } => {
+            let mut cases: Vec<(Int, BbName)> = cases.iter().collect();
+            cases.sort_by_key(|(case, _bb)| *case);
+            let mut out: Vec<_> =
+                cases.into_iter().map(|(case, bb)| (bb, case.to_string())).collect();
+            out.push((fallback, "otherwise".to_string()));
+            out
+        }
+        Terminator::Call { next_block, .. } | Terminator::Intrinsic { next_block, .. } =>
+            next_block.into_iter().map(|bb| (bb, "return".to_string())).collect(),
+        Terminator::Unreachable | Terminator::Return => vec![],
+    }
+}
+
+// Escapes a string for a DOT label, with all lines left-aligned.
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\l")
+}
@@ -72,6 +72,16 @@ pub(super) fn fmt_global_name(g: GlobalName) -> String {
     format!("global({id})")
 }
 
+pub(super) fn fmt_vtable_name(v: VTableName) -> String {
+    let id = v.0.get_internal();
+    format!("vtable({id})")
+}
+
+pub(super) fn fmt_trait_method_name(m: TraitMethodName) -> String {
+    let id = m.0.get_internal();
+    format!("method({id})")
+}
+
 pub(super) fn fmt_constant(c: Constant) -> FmtExpr {
     match c {
         Constant::Int(int) => FmtExpr::Atomic(int.to_string()),
@@ -84,6 +94,7 @@ pub(super) fn fmt_constant(c: Constant) -> FmtExpr {
             } else {
                 FmtExpr::Atomic(format!("invalid_ptr({addr})"))
             },
+        Constant::VTablePointer(vtable_name) => FmtExpr::Atomic(fmt_vtable_name(vtable_name)),
     }
 }
 
@@ -93,6 +104,7 @@ pub(super) fn fmt_value_expr(v: ValueExpr, comptypes: &mut Vec<CompType>) -> Fmt
         ValueExpr::Tuple(l, t) => {
             let (lparen, rparen) = match t {
                 Type::Array { .. } => ('[', ']'),
+                Type::Vector { .. } => ('<', '>'),
                 Type::Tuple { .. } => ('(', ')'),
                 _ => panic!(),
             };
@@ -115,26 +127,36 @@ pub(super) fn fmt_value_expr(v: ValueExpr, comptypes: &mut Vec<CompType>) -> Fmt
             let place = fmt_place_expr(place.extract(), comptypes).to_string();
             FmtExpr::Atomic(format!("discriminant({place})"))
         }
+        ValueExpr::Len { place } => {
+            let place = fmt_place_expr(place.extract(), comptypes).to_string();
+            FmtExpr::Atomic(format!("len({place})"))
+        }
+        ValueExpr::VTableMethodLookup { expression, method } => {
+            let expression = fmt_value_expr(expression.extract(), comptypes).to_string();
+            let method = fmt_trait_method_name(method);
+            FmtExpr::Atomic(format!("vtable_method({expression}, {method})"))
+        }
         ValueExpr::Load { source } => {
             let source = source.extract();
             let source = fmt_place_expr(source, comptypes).to_string();
             FmtExpr::Atomic(format!("load({source})"))
         }
-        ValueExpr::AddrOf { target, ptr_ty: PtrType::Raw { .. } } => {
+        ValueExpr::AddrOf { target, ptr_ty: PtrType::Raw { .. }, .. } => {
             let target = target.extract();
             let target = fmt_place_expr(target, comptypes).to_atomic_string();
             FmtExpr::NonAtomic(format!("&raw {target}"))
         }
-        ValueExpr::AddrOf { target, ptr_ty: PtrType::Ref { mutbl, .. } } => {
+        ValueExpr::AddrOf { target, ptr_ty: PtrType::Ref { mutbl, .. }, two_phase } => {
             let target = target.extract();
             let target = fmt_place_expr(target, comptypes).to_atomic_string();
-            let mutbl = match mutbl {
-                Mutability::Mutable => "mut ",
-                Mutability::Immutable => "",
+            let mutbl = match (mutbl, two_phase) {
+                (Mutability::Mutable, true) => "two_phase mut ",
+                (Mutability::Mutable, false) => "mut ",
+                (Mutability::Immutable, _) => "",
             };
             FmtExpr::NonAtomic(format!("&{mutbl}{target}"))
         }
-        ValueExpr::AddrOf { target: _, ptr_ty: _ } => {
+        ValueExpr::AddrOf { .. } => {
             panic!("unsupported ptr_ty for AddrOr!")
         }
         ValueExpr::UnOp { operator, operand } => {
@@ -155,6 +177,7 @@ pub(super) fn fmt_value_expr(v: ValueExpr, comptypes: &mut Vec<CompType>) -> Fmt
                     let new_ty = fmt_type(new_ty, comptypes).to_string();
                     FmtExpr::Atomic(format!("transmute<{new_ty}>({operand})"))
                 }
+                UnOp::GetThinPointer => FmtExpr::Atomic(format!("thin_ptr({operand})")),
             }
         }
         ValueExpr::BinOp { operator: BinOp::Int(int_op), left, right } => {
@@ -175,20 +198,21 @@ pub(super) fn fmt_value_expr(v: ValueExpr, comptypes: &mut Vec<CompType>) -> Fmt
             FmtExpr::NonAtomic(format!("{l} {int_op} {r}"))
         }
         ValueExpr::BinOp { operator: BinOp::IntRel(rel), left, right } => {
-            let rel = match rel {
-                IntRel::Lt => "<",
-                IntRel::Le => "<=",
-                IntRel::Gt => ">",
-                IntRel::Ge => ">=",
-                IntRel::Eq => "==",
-                IntRel::Ne => "!=",
-            };
+            let rel = fmt_int_rel(rel);
 
             let l = fmt_value_expr(left.extract(), comptypes).to_atomic_string();
             let r = fmt_value_expr(right.extract(), comptypes).to_atomic_string();
 
             FmtExpr::NonAtomic(format!("{l} {rel} {r}"))
         }
+        ValueExpr::BinOp { operator: BinOp::PtrRel(rel), left, right } => {
+            let rel = fmt_int_rel(rel);
+
+            let l = fmt_value_expr(left.extract(), comptypes).to_atomic_string();
+            let r = fmt_value_expr(right.extract(), comptypes).to_atomic_string();
+            // due to overlap with integer relations add <ptr> to operator
+            FmtExpr::NonAtomic(format!("{l} {rel}<ptr> {r}"))
+        }
         ValueExpr::BinOp { operator: BinOp::PtrOffset { inbounds }, left, right } => {
             let offset_name = match inbounds {
                 true => "offset_inbounds",
@@ -209,5 +233,22 @@ pub(super) fn fmt_value_expr(v: ValueExpr, comptypes: &mut Vec<CompType>) -> Fmt
             // due to overlap with integer binop add <bool> to operator
             FmtExpr::NonAtomic(format!("{l} {bool_op}<bool> {r}"))
         }
+        ValueExpr::BinOp { operator: BinOp::ConstructWidePointer(ptr_ty), left, right } => {
+            let ptr_ty = fmt_ptr_type(ptr_ty).to_string();
+            let l = fmt_value_expr(left.extract(), comptypes).to_string();
+            let r = fmt_value_expr(right.extract(), comptypes).to_string();
+            FmtExpr::Atomic(format!("wide_ptr<{ptr_ty}>({l}, {r})"))
+        }
+    }
+}
+
+fn fmt_int_rel(rel: IntRel) -> &'static str {
+    match rel {
+        IntRel::Lt => "<",
+        IntRel::Le => "<=",
+        IntRel::Gt => ">",
+        IntRel::Ge => ">=",
+        IntRel::Eq => "==",
+        IntRel::Ne => "!=",
     }
 }
@@ -1,9 +1,16 @@
 use super::*;
 
+use std::fmt::Write;
+
 // Formats all functions found within the program.
 // All composite types that are used within `prog` will be added to `comptypes` exactly once.
-pub(super) fn fmt_functions(prog: Program, comptypes: &mut Vec<CompType>) -> String {
-    let mut fns: Vec<(FnName, Function)> = prog.functions.iter().collect();
+pub(super) fn fmt_functions(
+    prog: Program,
+    filter: &impl Fn(FnName) -> bool,
+    comptypes: &mut Vec<CompType>,
+) -> String {
+    let mut fns: Vec<(FnName, Function)> =
+        prog.functions.iter().filter(|(fn_name, _fn)| filter(*fn_name)).collect();
 
     // Functions are formatted in the order given by their name.
     fns.sort_by_key(|(FnName(name), _fn)| *name);
@@ -66,7 +73,12 @@ fn fmt_function(
     out
 }
 
-fn fmt_bb(bb_name: BbName, bb: BasicBlock, start: bool, comptypes: &mut Vec<CompType>) -> String {
+pub(super) fn fmt_bb(
+    bb_name: BbName,
+    bb: BasicBlock,
+    start: bool,
+    comptypes: &mut Vec<CompType>,
+) -> String {
     let name = bb_name.0.get_internal();
 
     let mut out = if start { format!("  start bb{name}:\n") } else { format!("  bb{name}:\n") };
@@ -82,7 +94,7 @@ fn fmt_bb(bb_name: BbName, bb: BasicBlock, start: bool, comptypes: &mut Vec<Comp
     out
 }
 
-fn fmt_statement(st: Statement, comptypes: &mut Vec<CompType>) -> String {
+pub(super) fn fmt_statement(st: Statement, comptypes: &mut Vec<CompType>) -> String {
     match st {
         Statement::Assign { destination, source } => {
             let left = fmt_place_expr(destination, comptypes).to_string();
@@ -93,9 +105,14 @@ fn fmt_statement(st: Statement, comptypes: &mut Vec<CompType>) -> String {
             let left = fmt_place_expr(destination, comptypes).to_string();
             format!("    discriminant({left}) = {value};")
         }
-        Statement::Validate { place, fn_entry } => {
+        Statement::Validate { place, kind } => {
             let place = fmt_place_expr(place, comptypes).to_string();
-            format!("    validate({place}, {fn_entry});")
+            let kind = match kind {
+                RetagKind::FnEntry => "fn_entry",
+                RetagKind::TwoPhase => "two_phase",
+                RetagKind::Default => "default",
+            };
+            format!("    validate({place}, {kind});")
         }
         Statement::Deinit { place } => {
             let place = fmt_place_expr(place, comptypes).to_string();
@@ -135,7 +152,7 @@ fn fmt_call(
     format!("    {r} = {callee}({args}){next};")
 }
 
-fn fmt_terminator(t: Terminator, comptypes: &mut Vec<CompType>) -> String {
+pub(super) fn fmt_terminator(t: Terminator, comptypes: &mut Vec<CompType>) -> String {
     match t {
         Terminator::Goto(bb) => {
             let bb = fmt_bb_name(bb);
@@ -143,8 +160,10 @@ fn fmt_terminator(t: Terminator, comptypes: &mut Vec<CompType>) -> String {
         }
         Terminator::Switch { value, cases, fallback } => {
             let branch_expr = fmt_value_expr(value, comptypes).to_string();
+            let mut cases: Vec<(Int, BbName)> = cases.iter().collect();
+            cases.sort_by_key(|(constant, _successor)| *constant);
             let mut case_strs = cases
-                .iter()
+                .into_iter()
                 .map(|(constant, successor)| format!("{}: {}", constant, fmt_bb_name(successor)))
                 .collect::<Vec<String>>();
             case_strs.push(format!("otherwise: {}", fmt_bb_name(fallback)));
@@ -181,15 +200,38 @@ fn fmt_terminator(t: Terminator, comptypes: &mut Vec<CompType>) -> String {
         Terminator::Intrinsic { intrinsic, arguments, ret, next_block } => {
             let callee = match intrinsic {
                 IntrinsicOp::Assume => "assume",
+                IntrinsicOp::UnreachableUnchecked => "unreachable_unchecked",
+                IntrinsicOp::Likely => "likely",
+                IntrinsicOp::Unlikely => "unlikely",
+                IntrinsicOp::BlackBox => "black_box",
                 IntrinsicOp::Exit => "exit",
+                IntrinsicOp::Nondet { .. } => "nondet",
+                IntrinsicOp::Freeze => "freeze",
+                IntrinsicOp::Abort => "abort",
                 IntrinsicOp::PrintStdout => "print",
                 IntrinsicOp::PrintStderr => "eprint",
+                IntrinsicOp::WriteBytesStdout => "write_bytes_stdout",
+                IntrinsicOp::ReadStdin => "read_stdin",
                 IntrinsicOp::Allocate => "allocate",
                 IntrinsicOp::Deallocate => "deallocate",
+                IntrinsicOp::CopyNonOverlapping { .. } => "copy_nonoverlapping",
+                IntrinsicOp::MemSwap { .. } => "mem_swap",
+                IntrinsicOp::IsAllocated => "is_allocated",
+                IntrinsicOp::FrameDepth => "frame_depth",
                 IntrinsicOp::Spawn => "spawn",
                 IntrinsicOp::Join => "join",
-                IntrinsicOp::AtomicStore => "atomic_store",
-                IntrinsicOp::AtomicLoad => "atomic_load",
+                IntrinsicOp::CurrentThreadId => "current_thread_id",
+                IntrinsicOp::ThreadLocalAddr { .. } => "thread_local_addr",
+                IntrinsicOp::Park => "park",
+                IntrinsicOp::Unpark => "unpark",
+                IntrinsicOp::AtomicStore(AtomicOrdering::SeqCst) => "atomic_store",
+                IntrinsicOp::AtomicStore(AtomicOrdering::Relaxed) => "atomic_store_relaxed",
+                IntrinsicOp::AtomicStore(AtomicOrdering::Release) => "atomic_store_release",
+                IntrinsicOp::AtomicStore(AtomicOrdering::Acquire) => "atomic_store_acquire",
+                IntrinsicOp::AtomicLoad(AtomicOrdering::SeqCst) => "atomic_load",
+                IntrinsicOp::AtomicLoad(AtomicOrdering::Relaxed) => "atomic_load_relaxed",
+                IntrinsicOp::AtomicLoad(AtomicOrdering::Acquire) => "atomic_load_acquire",
+                IntrinsicOp::AtomicLoad(AtomicOrdering::Release) => "atomic_load_release",
                 IntrinsicOp::AtomicCompareExchange => "atomic_compare_exchange",
                 IntrinsicOp::AtomicFetchAndOp(binop) => fmt_fetch(binop),
                 IntrinsicOp::Lock(IntrinsicLockOp::Acquire) => "lock_acquire",
@@ -197,9 +239,50 @@ fn fmt_terminator(t: Terminator, comptypes: &mut Vec<CompType>) -> String {
                 IntrinsicOp::Lock(IntrinsicLockOp::Release) => "lock_release",
                 IntrinsicOp::PointerExposeProvenance => "pointer_expose_provenance",
                 IntrinsicOp::PointerWithExposedProvenance => "pointer_with_exposed_provenance",
+                IntrinsicOp::PtrGuaranteedCmp => "ptr_guaranteed_cmp",
+                IntrinsicOp::OffsetOf { .. } => "offset_of",
+                IntrinsicOp::TryIntCast => "try_int_cast",
+                IntrinsicOp::SaturatingIntCast => "saturating_int_cast",
+                IntrinsicOp::RotateLeft => "rotate_left",
+                IntrinsicOp::RotateRight => "rotate_right",
+                IntrinsicOp::ReverseBits => "reverse_bits",
+                IntrinsicOp::ByteSwap => "byte_swap",
+                IntrinsicOp::SizeOf { .. } => "size_of",
+                IntrinsicOp::AlignOf { .. } => "align_of",
+                IntrinsicOp::SizeOfVal { .. } => "size_of_val",
+                IntrinsicOp::AlignOfVal { .. } => "align_of_val",
+                IntrinsicOp::SimdBinOp(binop) => fmt_simd_bin_op(binop),
+                IntrinsicOp::SimdIntRel(rel) => fmt_simd_rel(rel),
+                IntrinsicOp::SimdExtract => "simd_extract",
+                IntrinsicOp::SimdInsert => "simd_insert",
+                IntrinsicOp::SimdShuffle => "simd_shuffle",
             };
-            let args: Vec<_> =
+            let mut args: Vec<_> =
                 arguments.iter().map(|arg| fmt_value_expr(arg, comptypes).to_string()).collect();
+            if let IntrinsicOp::OffsetOf { ty, path } = intrinsic {
+                // `OffsetOf` is parameterized by a type and a field path instead of arguments.
+                let ty = fmt_type(ty, comptypes).to_string();
+                let mut fmt_path = String::new();
+                for field in path.iter() {
+                    write!(fmt_path, ".{field}").unwrap();
+                }
+                args.push(format!("{ty}{fmt_path}"));
+            }
+            if let IntrinsicOp::SizeOf { ty }
+            | IntrinsicOp::AlignOf { ty }
+            | IntrinsicOp::SizeOfVal { ty }
+            | IntrinsicOp::AlignOfVal { ty }
+            | IntrinsicOp::CopyNonOverlapping { ty }
+            | IntrinsicOp::MemSwap { ty } = intrinsic
+            {
+                args.push(fmt_type(ty, comptypes).to_string());
+            }
+            if let IntrinsicOp::ThreadLocalAddr { global } = intrinsic {
+                args.push(fmt_global_name(global));
+            }
+            if let IntrinsicOp::Nondet { min, max } = intrinsic {
+                args.push(format!("{min}..={max}"));
+            }
             fmt_call(callee, args.join(", "), ret, next_block, comptypes)
         }
     }
@@ -214,12 +297,38 @@ fn fmt_fetch(binop: IntBinOp) -> &'static str {
     }
 }
 
-fn fmt_bb_name(bb: BbName) -> String {
+fn fmt_simd_bin_op(binop: IntBinOp) -> &'static str {
+    use IntBinOp as B;
+    match binop {
+        B::Add => "simd_add",
+        B::Sub => "simd_sub",
+        B::Mul => "simd_mul",
+        B::Div => "simd_div",
+        B::Rem => "simd_rem",
+        B::BitAnd => "simd_and",
+        B::BitOr => "simd_or",
+        B::BitXor => "simd_xor",
+    }
+}
+
+fn fmt_simd_rel(rel: IntRel) -> &'static str {
+    match rel {
+        IntRel::Lt => "simd_lt",
+        IntRel::Le => "simd_le",
+        IntRel::Gt => "simd_gt",
+        IntRel::Ge => "simd_ge",
+        IntRel::Eq => "simd_eq",
+        IntRel::Ne => "simd_ne",
+    }
+}
+
+pub(super) fn fmt_bb_name(bb: BbName) -> String {
     let id = bb.0.get_internal();
     format!("bb{id}")
 }
 
-pub(super) fn fmt_fn_name(fn_name: FnName) -> String {
+// Formats a function name the way it appears in the output of `fmt_program`.
+pub fn fmt_fn_name(fn_name: FnName) -> String {
     let id = fn_name.0.get_internal();
     format!("f{id}")
 }
@@ -25,7 +25,7 @@ pub(super) fn fmt_relocation(relocation: Relocation) -> FmtExpr {
     }
 }
 
-fn fmt_global(gname: GlobalName, global: Global) -> String {
+pub(super) fn fmt_global(gname: GlobalName, global: Global) -> String {
     let gname_str = fmt_global_name(gname);
     let bytes_str = fmt_bytes(global.bytes);
     let align = global.align.bytes();
@@ -56,3 +56,29 @@ fn fmt_bytes(bytes: List<Option<u8>>) -> String {
 
     b.join(" ")
 }
+
+pub(super) fn fmt_vtables(vtables: Map<VTableName, VTable>) -> String {
+    let mut out = String::new();
+
+    let mut vtables: Vec<(VTableName, VTable)> = vtables.iter().collect();
+
+    // The vtables are formatted in the order of their names.
+    vtables.sort_by_key(|(VTableName(name), _vtable)| *name);
+
+    for (vname, vtable) in vtables {
+        let vname_str = fmt_vtable_name(vname);
+        let size = vtable.size.bytes();
+        let align = vtable.align.bytes();
+        out += &format!("{vname_str} {{\n  size = {size} bytes,\n  align = {align} bytes,\n");
+
+        let mut methods: Vec<(TraitMethodName, FnName)> = vtable.methods.iter().collect();
+        methods.sort_by_key(|(TraitMethodName(name), _fn_name)| *name);
+        for (method, fn_name) in methods {
+            let method = fmt_trait_method_name(method);
+            let fn_name = fmt_fn_name(fn_name);
+            out += &format!("  {method} = {fn_name},\n");
+        }
+        out += "}\n\n";
+    }
+    out
+}
@@ -0,0 +1,26 @@
+use super::*;
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+// The derived `Hash` impls of the spec types hash the `GcCow`s inside them by identity, while `==`
+// compares them by value. These functions hash by value instead: equal values are formatted the
+// same, so they also get the same hash. This relies on the formatting visiting `Map`s in key order.
+
+// Hash a program by value.
+pub fn program_hash(prog: Program) -> u64 {
+    hash_str(&fmt_program(prog))
+}
+
+// Hash a type by value.
+pub fn type_hash(ty: Type) -> u64 {
+    let mut comptypes: Vec<CompType> = Vec::new();
+    let ty = fmt_type(ty, &mut comptypes).to_string();
+    hash_str(&(fmt_comptypes(comptypes) + &ty))
+}
+
+fn hash_str(s: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    s.hash(&mut hasher);
+    hasher.finish()
+}
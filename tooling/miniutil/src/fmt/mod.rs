@@ -5,6 +5,7 @@ use expr::*;
 
 mod function;
 use function::*;
+pub use function::fmt_fn_name;
 
 mod ty;
 use ty::*;
@@ -12,6 +13,18 @@ use ty::*;
 mod global;
 use global::*;
 
+mod dot;
+pub use dot::to_dot;
+
+mod value;
+pub use value::value_to_string;
+
+mod diff;
+pub use diff::program_diff;
+
+mod hash;
+pub use hash::{program_hash, type_hash};
+
 // Print a program to stdout.
 pub fn dump_program(prog: Program) {
     let s = fmt_program(prog);
@@ -20,11 +33,18 @@ pub fn dump_program(prog: Program) {
 
 // Format a program into a string.
 pub fn fmt_program(prog: Program) -> String {
+    fmt_program_filtered(prog, |_fn_name| true)
+}
+
+// Format a program into a string, but only include the functions for which `filter` returns true.
+// Composite types, globals and vtables are always included.
+pub fn fmt_program_filtered(prog: Program, filter: impl Fn(FnName) -> bool) -> String {
     let mut comptypes: Vec<CompType> = Vec::new();
 
-    let functions_string = fmt_functions(prog, &mut comptypes);
+    let functions_string = fmt_functions(prog, &filter, &mut comptypes);
     let comptypes_string = fmt_comptypes(comptypes);
     let globals_string = fmt_globals(prog.globals);
+    let vtables_string = fmt_vtables(prog.vtables);
 
-    comptypes_string + &functions_string + &globals_string
+    comptypes_string + &functions_string + &globals_string + &vtables_string
 }
@@ -14,6 +14,15 @@ pub(super) fn fmt_type(t: Type, comptypes: &mut Vec<CompType>) -> FmtExpr {
             let elem = fmt_type(elem.extract(), comptypes).to_string();
             FmtExpr::Atomic(format!("[{elem}; {count}]"))
         }
+        Type::Vector { elem, count } => {
+            let elem = fmt_int_type(elem);
+            FmtExpr::Atomic(format!("simd<{elem}; {count}>"))
+        }
+        Type::Slice { elem } => {
+            let elem = fmt_type(elem.extract(), comptypes).to_string();
+            FmtExpr::Atomic(format!("[{elem}]"))
+        }
+        Type::TraitObject => FmtExpr::Atomic("dyn".to_string()),
     }
 }
 
@@ -41,7 +50,10 @@ pub(super) fn fmt_ptr_type(ptr_ty: PtrType) -> FmtExpr {
             let layout_str = fmt_layout(pointee);
             FmtExpr::Atomic(format!("Box<{layout_str}>"))
         }
-        PtrType::Raw => FmtExpr::NonAtomic(format!("*raw")),
+        PtrType::Raw { meta_kind } => {
+            let meta_str = fmt_meta_kind(meta_kind);
+            FmtExpr::NonAtomic(format!("*raw{meta_str}"))
+        }
         PtrType::FnPtr(conv) => FmtExpr::Atomic(format!("fn({conv:?})")),
     }
 }
@@ -53,7 +65,16 @@ fn fmt_layout(layout: Layout) -> String {
         true => "",
         false => ", uninhabited",
     };
-    format!("layout(size={size}, align={align}{uninhab_str})")
+    let meta_str = fmt_meta_kind(layout.meta_kind);
+    format!("layout(size={size}, align={align}{uninhab_str}{meta_str})")
+}
+
+fn fmt_meta_kind(meta_kind: PointerMetaKind) -> &'static str {
+    match meta_kind {
+        PointerMetaKind::None => "",
+        PointerMetaKind::ElementCount => " slice",
+        PointerMetaKind::VTablePointer => " dyn",
+    }
 }
 
 /////////////////////
@@ -113,7 +134,7 @@ pub(super) fn fmt_comptypes(mut comptypes: Vec<CompType>) -> String {
 
 fn fmt_comptype(i: CompTypeIndex, t: CompType, comptypes: &mut Vec<CompType>) -> String {
     let (keyword, size, align) = match t.0 {
-        Type::Tuple { fields: _, size, align } => ("tuple", size, align),
+        Type::Tuple { size, align, .. } => ("tuple", size, align),
         Type::Union { size, align, .. } => ("union", size, align),
         Type::Enum { size, align, .. } => ("enum", size, align),
         _ => panic!("not a supported composite type!"),
@@ -123,7 +144,12 @@ fn fmt_comptype(i: CompTypeIndex, t: CompType, comptypes: &mut Vec<CompType>) ->
     let align = align.bytes();
     let mut s = format!("{keyword} {ct} ({size} bytes, aligned {align} bytes) {{\n");
     match t.0 {
-        Type::Tuple { fields, .. } => s += &fmt_comptype_fields(fields, comptypes),
+        Type::Tuple { fields, valid_range, .. } => {
+            s += &fmt_comptype_fields(fields, comptypes);
+            if let Some((start, end)) = valid_range {
+                s += &format!("  valid_range({start}..={end}),\n");
+            }
+        }
         Type::Union { fields, chunks, .. } => {
             s += &fmt_comptype_fields(fields, comptypes);
             s += &fmt_comptype_chunks(chunks);
@@ -131,7 +157,10 @@ fn fmt_comptype(i: CompTypeIndex, t: CompType, comptypes: &mut Vec<CompType>) ->
         Type::Enum { variants, discriminant_ty, .. } => {
             let discr = fmt_int_type(discriminant_ty);
             s += &format!("  Discriminant: {discr}\n");
-            variants.iter().for_each(|(discriminant, v)| {
+            // `Map` iteration order is arbitrary, the output should not be.
+            let mut variants: Vec<(Int, Variant)> = variants.iter().collect();
+            variants.sort_by_key(|(discriminant, _v)| *discriminant);
+            variants.into_iter().for_each(|(discriminant, v)| {
                 let typ = fmt_type(v.ty, comptypes).to_string();
                 s += &format!("  Variant {discriminant}: {typ}\n");
             });
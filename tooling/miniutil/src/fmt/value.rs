@@ -0,0 +1,105 @@
+use super::*;
+
+/// Renders a runtime value of type `ty` as a Rust-like literal.
+/// Enum variants have no names in MiniRust, so they are printed by discriminant,
+/// e.g. `variant1((3, 4))`. Pointers are printed with the provenance they carry.
+pub fn value_to_string<M: Memory>(value: Value<M>, ty: Type) -> String
+where
+    M::Provenance: std::fmt::Debug,
+{
+    match (value, ty) {
+        (Value::Int(i), Type::Int(_)) => format!("{i}"),
+        (Value::Bool(b), Type::Bool) => format!("{b}"),
+        (Value::Ptr(ptr), Type::Ptr(ptr_ty)) => fmt_pointer::<M>(ptr, ptr_ty),
+        (Value::WidePtr(ptr, meta), Type::Ptr(ptr_ty)) => {
+            let ptr = fmt_pointer::<M>(ptr, ptr_ty);
+            match meta {
+                PointerMeta::ElementCount(count) => format!("{ptr} with length {count}"),
+                PointerMeta::VTablePointer(addr) => {
+                    let addr = addr.try_to_usize().unwrap();
+                    format!("{ptr} with vtable 0x{addr:x}")
+                }
+            }
+        }
+        (Value::Tuple(vals), Type::Tuple { fields, .. }) => {
+            let tys: Vec<Type> = fields.iter().map(|(_offset, ty)| ty).collect();
+            let s = fmt_elems::<M>(vals, &tys);
+            // A 1-tuple needs a trailing comma to not be mistaken for parentheses.
+            if tys.len() == 1 { format!("({s},)") } else { format!("({s})") }
+        }
+        (Value::Tuple(vals), Type::Array { elem, .. }) => {
+            let tys: Vec<Type> = vals.iter().map(|_| elem.extract()).collect();
+            format!("[{}]", fmt_elems::<M>(vals, &tys))
+        }
+        (Value::Tuple(vals), Type::Vector { elem, .. }) => {
+            let tys: Vec<Type> = vals.iter().map(|_| Type::Int(elem)).collect();
+            format!("simd[{}]", fmt_elems::<M>(vals, &tys))
+        }
+        (Value::Variant { discriminant, data }, Type::Enum { variants, .. }) => {
+            let data_ty = variants.get(discriminant).unwrap().ty;
+            match (data.extract(), data_ty) {
+                // Field-less variants are printed like `None`.
+                (Value::Tuple(vals), Type::Tuple { fields, .. }) if fields.is_empty() => {
+                    assert!(vals.is_empty());
+                    format!("variant{discriminant}")
+                }
+                // The fields of a tuple variant are printed like `Some(3)`.
+                (Value::Tuple(vals), Type::Tuple { fields, .. }) => {
+                    let tys: Vec<Type> = fields.iter().map(|(_offset, ty)| ty).collect();
+                    format!("variant{discriminant}({})", fmt_elems::<M>(vals, &tys))
+                }
+                (data, data_ty) =>
+                    format!("variant{discriminant}({})", value_to_string::<M>(data, data_ty)),
+            }
+        }
+        (Value::Union(chunks), Type::Union { .. }) => {
+            let chunks: Vec<String> = chunks
+                .iter()
+                .map(|chunk| {
+                    let bytes: Vec<String> = chunk.iter().map(fmt_abstract_byte::<M>).collect();
+                    format!("[{}]", bytes.join(", "))
+                })
+                .collect();
+            format!("union{{ {} }}", chunks.join(", "))
+        }
+        (_, ty) => panic!("value_to_string: value does not fit type {ty:?}"),
+    }
+}
+
+fn fmt_elems<M: Memory>(vals: List<Value<M>>, tys: &[Type]) -> String
+where
+    M::Provenance: std::fmt::Debug,
+{
+    assert!(vals.len() == Int::from(tys.len()));
+    let elems: Vec<String> =
+        vals.iter().zip(tys.iter()).map(|(val, ty)| value_to_string::<M>(val, *ty)).collect();
+    elems.join(", ")
+}
+
+fn fmt_pointer<M: Memory>(ptr: Pointer<M::Provenance>, ptr_ty: PtrType) -> String
+where
+    M::Provenance: std::fmt::Debug,
+{
+    let addr = ptr.addr.try_to_usize().unwrap();
+    let sigil = match ptr_ty {
+        PtrType::Ref { mutbl: Mutability::Mutable, .. } => "&mut ",
+        PtrType::Ref { mutbl: Mutability::Immutable, .. } => "&",
+        PtrType::Box { .. } => "box ",
+        PtrType::Raw { .. } | PtrType::FnPtr(_) => "",
+    };
+    match ptr.provenance {
+        Some(prov) => format!("{sigil}0x{addr:x} ({prov:?})"),
+        None => format!("{sigil}0x{addr:x} (no provenance)"),
+    }
+}
+
+fn fmt_abstract_byte<M: Memory>(byte: AbstractByte<M::Provenance>) -> String
+where
+    M::Provenance: std::fmt::Debug,
+{
+    match byte {
+        AbstractByte::Uninit => "<uninit>".to_string(),
+        AbstractByte::Init(data, None) => format!("0x{data:02x}"),
+        AbstractByte::Init(data, Some(prov)) => format!("0x{data:02x} ({prov:?})"),
+    }
+}
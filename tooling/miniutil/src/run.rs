@@ -1,51 +1,218 @@
+use std::collections::HashMap;
+
 use crate::{mock_write::MockWrite, *};
 
+/// A function, a basic block in it, and the index of a statement in that block.
+/// The index `statements.len()` refers to the terminator.
+pub type Location = (FnName, BbName, Int);
+
+/// How often each block was entered, counting calls and jumps to it.
+type BlockEntries = HashMap<(FnName, BbName), usize>;
+
+/// The block that a program entered most often before it hit the step limit.
+/// If that block was entered many times, the program was probably stuck in a loop starting there;
+/// if no block was entered more than once, it was making linear progress.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HottestBlock {
+    pub fn_name: FnName,
+    pub block: BbName,
+    /// How often `block` was entered.
+    pub entries: usize,
+}
+
+/// The call depth at which `RunConfig::default()` reports a stack overflow.
+pub const DEFAULT_STACK_LIMIT: usize = 10_000;
+
+/// Options for running a program: limits that tools impose, which the Abstract Machine itself
+/// has no notion of, and the `MachineConfig`.
+#[derive(Clone, Copy, Debug)]
+pub struct RunConfig {
+    /// Stop with `TerminationInfo::StepLimit` after this many steps.
+    pub step_limit: Option<usize>,
+    /// Stop with `TerminationInfo::StackOverflow` once a thread has more stack frames than this.
+    pub stack_limit: Option<usize>,
+    /// Stop with `TerminationInfo::StackOverflow` once the locals of all stack frames of a thread
+    /// take up more than this many bytes.
+    pub stack_bytes: Option<usize>,
+    /// Allow the program to use test-only intrinsics like `IsAllocated`.
+    pub test_intrinsics: bool,
+    /// Check that safe pointers are dereferenceable on every load, not just on retags.
+    pub check_dereferenceable_on_load: bool,
+    /// How to pick the thread that takes the next step.
+    pub scheduler: SchedulerMode,
+    /// Whether integer-to-pointer casts may pick up provenance that was never exposed.
+    pub provenance_model: ProvenanceModel,
+    /// Freeze uninitialized bytes deterministically from this seed.
+    pub freeze_seed: Option<u64>,
+}
+
+impl Default for RunConfig {
+    fn default() -> Self {
+        RunConfig {
+            step_limit: None,
+            stack_limit: Some(DEFAULT_STACK_LIMIT),
+            stack_bytes: None,
+            test_intrinsics: false,
+            check_dereferenceable_on_load: false,
+            scheduler: SchedulerMode::Nondet,
+            provenance_model: ProvenanceModel::Strict,
+            freeze_seed: None,
+        }
+    }
+}
+
 /// Run the program and return its TerminationInfo.
 /// Stdout/stderr are just forwarded to the host.
 pub fn run_program(prog: Program) -> TerminationInfo {
+    run_program_with_location(prog).0
+}
+
+/// Like `run_program`, but also returns the location that the active thread was about to
+/// execute when the program stopped. In case of UB, this is where the UB happened.
+pub fn run_program_with_location(prog: Program) -> (TerminationInfo, Option<Location>) {
+    let out = std::io::stdout();
+    let err = std::io::stderr();
+
+    run::<DefaultTarget>(prog, &[], out, err, RunConfig::default(), &mut BlockEntries::new())
+}
+
+/// Like `run_program`, but stops with `TerminationInfo::StepLimit` if the program
+/// takes more than `limit` steps.
+pub fn run_program_with_step_limit(prog: Program, limit: usize) -> TerminationInfo {
+    run_program_with_config(prog, RunConfig { step_limit: Some(limit), ..RunConfig::default() })
+}
+
+/// Like `run_program_with_step_limit`, but when the step limit is hit, also reports the block
+/// that was entered most often, to tell an infinite loop apart from a long computation.
+pub fn run_program_with_step_limit_report(
+    prog: Program,
+    limit: usize,
+) -> (TerminationInfo, Option<HottestBlock>) {
     let out = std::io::stdout();
     let err = std::io::stderr();
+    let config = RunConfig { step_limit: Some(limit), ..RunConfig::default() };
 
-    let res: Result<!, TerminationInfo> = run(prog, out, err);
-    match res {
-        Ok(never) => never,
-        Err(t) => t,
+    let mut block_entries = BlockEntries::new();
+    let (info, _) = run::<DefaultTarget>(prog, &[], out, err, config, &mut block_entries);
+    if info != TerminationInfo::StepLimit {
+        return (info, None);
     }
+    // Break ties deterministically, in favor of the block that comes first.
+    let hottest = block_entries
+        .into_iter()
+        .map(|((fn_name, block), entries)| HottestBlock { fn_name, block, entries })
+        .max_by_key(|b| {
+            let position = (b.fn_name.0.get_internal(), b.block.0.get_internal());
+            (b.entries, std::cmp::Reverse(position))
+        });
+    (info, hottest)
+}
+
+/// Like `run_program`, but with the limits given by `config`.
+pub fn run_program_with_config(prog: Program, config: RunConfig) -> TerminationInfo {
+    let out = std::io::stdout();
+    let err = std::io::stderr();
+
+    run::<DefaultTarget>(prog, &[], out, err, config, &mut BlockEntries::new()).0
 }
 
 /// Run the program and return stdout as a `Vec<String>`  or a termination info
 /// if it did not terminate correctly. Stderr is just forwarded to the host.
 pub fn get_stdout(prog: Program) -> Result<Vec<String>, TerminationInfo> {
+    get_stdout_with_stdin(prog, &[])
+}
+
+/// Like `get_stdout`, but the program reads `stdin` as its input.
+pub fn get_stdout_with_stdin(prog: Program, stdin: &[u8]) -> Result<Vec<String>, TerminationInfo> {
+    get_stdout_impl::<DefaultTarget>(prog, stdin, RunConfig::default())
+}
+
+/// Like `get_stdout`, but runs the program on target `T` instead of the `DefaultTarget`.
+pub fn get_stdout_for_target<T: Target>(prog: Program) -> Result<Vec<String>, TerminationInfo> {
+    get_stdout_impl::<T>(prog, &[], RunConfig::default())
+}
+
+/// Like `get_stdout`, but with the options given by `config`.
+pub fn get_stdout_with_config(
+    prog: Program,
+    config: RunConfig,
+) -> Result<Vec<String>, TerminationInfo> {
+    get_stdout_impl::<DefaultTarget>(prog, &[], config)
+}
+
+fn get_stdout_impl<T: Target>(
+    prog: Program,
+    stdin: &[u8],
+    config: RunConfig,
+) -> Result<Vec<String>, TerminationInfo> {
     let out = MockWrite::new();
     let err = std::io::stderr();
 
-    let res = run(prog, out.clone(), err);
-    match res {
-        Ok(never) => never,
-        Err(TerminationInfo::MachineStop) => Ok(out.into_strings()),
-        Err(info) => Err(info),
+    match run::<T>(prog, stdin, out.clone(), err, config, &mut BlockEntries::new()).0 {
+        TerminationInfo::MachineStop(_) => Ok(out.into_strings()),
+        info => Err(info),
     }
 }
 
-/// Run the program to completion using the given writers for stdout/stderr.
+/// Run the program to completion on target `T` using the given input and writers for stdout/stderr.
+/// Execution stops early if it exceeds one of the limits in `config`.
+/// Also returns the location of the active thread when the program stopped.
+/// If there is a step limit, every block entry is counted in `block_entries`.
 ///
 /// We fix `BasicMemory` as a memory for now.
-fn run(prog: Program, stdout: impl GcWrite, stderr: impl GcWrite) -> Result<!, TerminationInfo> {
-    let res: NdResult<!> = try {
-        let mut machine = Machine::<BasicMemory<DefaultTarget>>::new(
-            prog,
-            DynWrite::new(stdout),
-            DynWrite::new(stderr),
-        )?;
-
-        loop {
-            machine.step()?;
-
-            // Drops everything not reachable from `machine`.
-            mark_and_sweep(&machine);
-        }
+fn run<T: Target>(
+    prog: Program,
+    stdin: &[u8],
+    stdout: impl GcWrite,
+    stderr: impl GcWrite,
+    config: RunConfig,
+    block_entries: &mut BlockEntries,
+) -> (TerminationInfo, Option<Location>) {
+    let machine = Machine::<BasicMemory<T>>::new(
+        prog,
+        MachineConfig {
+            test_intrinsics: config.test_intrinsics,
+            check_dereferenceable_on_load: config.check_dereferenceable_on_load,
+            scheduler: config.scheduler,
+            provenance_model: config.provenance_model,
+            freeze_seed: config.freeze_seed.map(Int::from),
+        },
+        stdin.iter().copied().collect(),
+        DynWrite::new(stdout),
+        DynWrite::new(stderr),
+    );
+    let mut machine = match machine.get_internal() {
+        Ok(machine) => machine,
+        Err(info) => return (info, None),
     };
 
-    // Extract the TerminationInfo from the `NdResult<!>`.
-    res.get_internal()
+    let mut steps = 0;
+    loop {
+        // Right after a jump or call, the active thread is at the start of a block.
+        if config.step_limit.is_some() {
+            if let Some((fn_name, block, stmt)) = machine.current_location() {
+                if stmt == Int::ZERO {
+                    *block_entries.entry((fn_name, block)).or_default() += 1;
+                }
+            }
+        }
+        if config.step_limit.is_some_and(|limit| steps >= limit) {
+            return (TerminationInfo::StepLimit, machine.current_location());
+        }
+        if let Err(info) = machine.step().get_internal() {
+            return (info, machine.current_location());
+        }
+        steps += 1;
+        // Only the active thread can have pushed a stack frame in this step.
+        let too_deep =
+            config.stack_limit.is_some_and(|limit| machine.stack_depth() > Int::from(limit));
+        let too_large =
+            config.stack_bytes.is_some_and(|limit| machine.stack_size().bytes() > Int::from(limit));
+        if too_deep || too_large {
+            return (TerminationInfo::StackOverflow, machine.current_location());
+        }
+
+        // Drops everything not reachable from `machine`.
+        mark_and_sweep(&machine);
+    }
 }